@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use navdata_components::coordinate::{regex_parser, Coordinate};
+
+const INPUTS: [&str; 8] = [
+    "N400000E1160000",
+    "N403003.6E1163003.6",
+    "N39113625E116342500",
+    "N4030.60E11630.60",
+    "N040.30.03.600 E116.30.03.600",
+    "N 40 30.6 E 116 30.6",
+    "40.123456,116.654321",
+    "40.12l3456,116.654321",
+];
+
+fn bench_parse(c: &mut Criterion) {
+    c.bench_function("scanner", |b| {
+        b.iter(|| {
+            for s in INPUTS {
+                let _ = black_box(Coordinate::from_str(black_box(s)));
+            }
+        })
+    });
+
+    c.bench_function("regex", |b| {
+        b.iter(|| {
+            for s in INPUTS {
+                let _ = black_box(regex_parser::parse(black_box(s)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
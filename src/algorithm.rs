@@ -1,3 +1,8 @@
+pub mod bearing;
+pub mod densify;
+pub mod geodesic;
+pub mod intersection;
 pub mod measurement;
 pub mod position_transformation;
 pub mod region;
+pub mod rhumb;
@@ -0,0 +1,155 @@
+//! 带基准的方位：真方位和磁方位
+//!
+//! 磁偏角东正西负，真方位 = 磁方位 + 磁偏角。
+//! 两种方位是不同的类型，只能通过`to_true`、`to_magnetic`显式换算，避免混用
+//!
+//! 接受方位的函数按类型分为两组：使用磁方位的函数同时需要磁偏角，名称带`_true`的函数使用真方位，不需要磁偏角
+
+use super::measurement::normalize_bearing;
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Sub};
+
+/// 转弯方向
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy, Clone)]
+pub enum TurnDirection {
+    /// 左转（逆时针）
+    Left,
+    /// 右转（顺时针）
+    Right,
+    /// 不需要转弯
+    Straight,
+}
+
+/// 从`from`转到`to`的有符号角度，顺时针为正，范围[-180, 180)
+fn signed_angle(from: f64, to: f64) -> f64 {
+    let angle = normalize_bearing(to - from);
+    if angle >= 180.0 {
+        angle - 360.0
+    } else {
+        angle
+    }
+}
+
+/// 从`from`转到`to`时较小的转弯方向，相差180°时为右转
+fn turn_direction(from: f64, to: f64) -> TurnDirection {
+    let angle = normalize_bearing(to - from);
+    if angle == 0.0 {
+        TurnDirection::Straight
+    } else if angle <= 180.0 {
+        TurnDirection::Right
+    } else {
+        TurnDirection::Left
+    }
+}
+
+macro_rules! bearing_type {
+    ($name:ident) => {
+        impl $name {
+            /// 创建方位，度数规范化到[0, 360)范围
+            pub fn new(degrees: f64) -> Self {
+                $name(normalize_bearing(degrees))
+            }
+
+            /// 度数，范围[0, 360)
+            pub fn degrees(&self) -> f64 {
+                self.0
+            }
+
+            /// 反方向
+            pub fn reciprocal(&self) -> Self {
+                $name::new(self.0 + 180.0)
+            }
+
+            /// 从本方位转到`other`的有符号角度，顺时针为正，范围[-180, 180)
+            pub fn angle_to(&self, other: $name) -> f64 {
+                signed_angle(self.0, other.0)
+            }
+
+            /// 从本方位转到`other`时较小的转弯方向，相差180°时为右转
+            pub fn turn_to(&self, other: $name) -> TurnDirection {
+                turn_direction(self.0, other.0)
+            }
+        }
+
+        impl Add<f64> for $name {
+            type Output = $name;
+
+            /// 顺时针旋转
+            fn add(self, degrees: f64) -> $name {
+                $name::new(self.0 + degrees)
+            }
+        }
+
+        impl Sub<f64> for $name {
+            type Output = $name;
+
+            /// 逆时针旋转
+            fn sub(self, degrees: f64) -> $name {
+                $name::new(self.0 - degrees)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = f64;
+
+            /// 有符号的差，即`rhs.angle_to(self)`，范围[-180, 180)
+            fn sub(self, rhs: $name) -> f64 {
+                rhs.angle_to(self)
+            }
+        }
+
+        impl PartialEq<f64> for $name {
+            fn eq(&self, other: &f64) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl From<$name> for f64 {
+            fn from(bearing: $name) -> f64 {
+                bearing.0
+            }
+        }
+
+        /// 度数规范化到[0, 360)范围，反序列化也经过这里
+        impl From<f64> for $name {
+            fn from(degrees: f64) -> Self {
+                $name::new(degrees)
+            }
+        }
+    };
+}
+
+/// 真方位（相对真北），单位为度，范围[0, 360)
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize, Copy, Clone, Default)]
+#[serde(from = "f64", into = "f64")]
+pub struct TrueBearing(f64);
+
+/// 磁方位（相对磁北），单位为度，范围[0, 360)
+#[derive(Debug, PartialEq, PartialOrd, Serialize, Deserialize, Copy, Clone, Default)]
+#[serde(from = "f64", into = "f64")]
+pub struct MagneticBearing(f64);
+
+bearing_type!(TrueBearing);
+bearing_type!(MagneticBearing);
+
+impl TrueBearing {
+    /// 换算为磁方位
+    ///
+    /// # 参数
+    ///
+    /// - `mag_var` - 磁偏角（东正西负）
+    pub fn to_magnetic(&self, mag_var: f64) -> MagneticBearing {
+        MagneticBearing::new(self.0 - mag_var)
+    }
+}
+
+impl MagneticBearing {
+    /// 换算为真方位
+    ///
+    /// # 参数
+    ///
+    /// - `mag_var` - 磁偏角（东正西负）
+    pub fn to_true(&self, mag_var: f64) -> TrueBearing {
+        TrueBearing::new(self.0 + mag_var)
+    }
+}
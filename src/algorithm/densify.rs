@@ -0,0 +1,251 @@
+//! 沿测地线加密航段和区域边界
+
+use crate::algorithm::geodesic::Geodesic;
+use crate::coordinate::normalize_lon;
+use crate::{Coordinate, Ellipsoid};
+
+/// 加密时每条边最多分成的段数，间隔过小时改为等分，避免生成过多的点
+pub const MAX_DENSIFY_SEGMENTS: usize = 10_000;
+
+/// 两点之间的测地线，起点方位角和距离只计算一次
+struct Leg {
+    geodesic: Geodesic,
+    raw: Coordinate,
+    azimuth: f64,
+    distance: f64,
+}
+
+impl Leg {
+    fn new(raw: &Coordinate, target: &Coordinate, ellipsoid: &Ellipsoid) -> Self {
+        let geodesic = Geodesic::new(ellipsoid);
+        let inverse = geodesic.inverse(raw, target);
+        Leg {
+            geodesic,
+            raw: *raw,
+            azimuth: inverse.initial_azimuth,
+            distance: inverse.distance,
+        }
+    }
+
+    /// 距起点`distance`米处的点
+    fn at(&self, distance: f64) -> Coordinate {
+        let coord = self
+            .geodesic
+            .direct(&self.raw, self.azimuth, distance)
+            .coord;
+        Coordinate {
+            lat: coord.lat,
+            lon: normalize_lon(coord.lon),
+        }
+    }
+}
+
+/// 获取WGS-84模型下两点之间测地线上按比例确定的点
+///
+/// # 参数
+///
+/// - `raw` - 起点
+/// - `target` - 终点
+/// - `fraction` - 比例，0为起点，1为终点，超出[0, 1]时沿测地线外推
+///
+/// # 返回值
+///
+/// 测地线上的点，经度在[-180, 180)范围内
+pub fn get_intermediate_coord(raw: &Coordinate, target: &Coordinate, fraction: f64) -> Coordinate {
+    get_intermediate_coord_with(raw, target, fraction, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下两点之间测地线上按比例确定的点
+///
+/// # 参数
+///
+/// - `raw` - 起点
+/// - `target` - 终点
+/// - `fraction` - 比例，0为起点，1为终点，超出[0, 1]时沿测地线外推
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 测地线上的点，经度在[-180, 180)范围内
+pub fn get_intermediate_coord_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    fraction: f64,
+    ellipsoid: &Ellipsoid,
+) -> Coordinate {
+    let leg = Leg::new(raw, target, ellipsoid);
+    leg.at(leg.distance * fraction)
+}
+
+/// 获取WGS-84模型下两点之间测地线的中点
+///
+/// # 参数
+///
+/// - `raw` - 起点
+/// - `target` - 终点
+///
+/// # 返回值
+///
+/// 到两点距离相等的测地线上的点
+pub fn get_midpoint(raw: &Coordinate, target: &Coordinate) -> Coordinate {
+    get_intermediate_coord(raw, target, 0.5)
+}
+
+/// 获取指定椭球下两点之间测地线的中点
+///
+/// # 参数
+///
+/// - `raw` - 起点
+/// - `target` - 终点
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 到两点距离相等的测地线上的点
+pub fn get_midpoint_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> Coordinate {
+    get_intermediate_coord_with(raw, target, 0.5, ellipsoid)
+}
+
+/// 获取WGS-84模型下将测地线等分的点
+///
+/// # 参数
+///
+/// - `raw` - 起点
+/// - `target` - 终点
+/// - `count` - 点的个数，测地线被分为`count + 1`段
+///
+/// # 返回值
+///
+/// 等分点，不含起点和终点，按从起点到终点的顺序排列
+pub fn get_intermediate_coords(
+    raw: &Coordinate,
+    target: &Coordinate,
+    count: usize,
+) -> Vec<Coordinate> {
+    get_intermediate_coords_with(raw, target, count, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下将测地线等分的点
+///
+/// # 参数
+///
+/// - `raw` - 起点
+/// - `target` - 终点
+/// - `count` - 点的个数，测地线被分为`count + 1`段
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 等分点，不含起点和终点，按从起点到终点的顺序排列
+pub fn get_intermediate_coords_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    count: usize,
+    ellipsoid: &Ellipsoid,
+) -> Vec<Coordinate> {
+    let leg = Leg::new(raw, target, ellipsoid);
+    let step = leg.distance / (count + 1) as f64;
+    (1..=count).map(|i| leg.at(step * i as f64)).collect()
+}
+
+/// 获取WGS-84模型下沿测地线每隔固定距离的点
+///
+/// # 参数
+///
+/// - `raw` - 起点
+/// - `target` - 终点
+/// - `spacing` - 间隔，单位为米
+///
+/// # 返回值
+///
+/// 从起点开始每隔`spacing`米的点，最后为终点，最后一段可能较短。
+/// 间隔不是正数时只返回起点和终点；需要超过`MAX_DENSIFY_SEGMENTS`段时改为等分成`MAX_DENSIFY_SEGMENTS`段，
+/// 即最多返回`MAX_DENSIFY_SEGMENTS + 1`个点
+pub fn densify(raw: &Coordinate, target: &Coordinate, spacing: f64) -> Vec<Coordinate> {
+    densify_with(raw, target, spacing, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下沿测地线每隔固定距离的点
+///
+/// # 参数
+///
+/// - `raw` - 起点
+/// - `target` - 终点
+/// - `spacing` - 间隔，单位为米
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 从起点开始每隔`spacing`米的点，最后为终点，最后一段可能较短。
+/// 间隔不是正数时只返回起点和终点；需要超过`MAX_DENSIFY_SEGMENTS`段时改为等分成`MAX_DENSIFY_SEGMENTS`段，
+/// 即最多返回`MAX_DENSIFY_SEGMENTS + 1`个点
+pub fn densify_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    spacing: f64,
+    ellipsoid: &Ellipsoid,
+) -> Vec<Coordinate> {
+    let mut coords = vec![*raw];
+    if spacing > 0.0 {
+        let leg = Leg::new(raw, target, ellipsoid);
+        // 终点前不足1毫米的点省略，避免与终点重合
+        let count = ((leg.distance - 1e-3) / spacing).ceil().max(1.0);
+        let (count, spacing) = if count > MAX_DENSIFY_SEGMENTS as f64 {
+            let count = MAX_DENSIFY_SEGMENTS;
+            (count, leg.distance / count as f64)
+        } else {
+            (count as usize, spacing)
+        };
+        coords.extend((1..count).map(|i| leg.at(spacing * i as f64)));
+    }
+    coords.push(*target);
+    coords
+}
+
+/// 沿测地线加密WGS-84模型下的多边形边界
+///
+/// 加密后相邻点的间隔足够小时，可以用`region::is_point_in_area`等平面方法近似判断，
+/// 边界不能跨越180°经线
+///
+/// # 参数
+///
+/// - `ring` - 边界上的点，最后一点与第一点之间的边自动闭合，不需要重复第一点
+/// - `spacing` - 最大间隔，单位为米
+///
+/// # 返回值
+///
+/// 加密后的边界，同样不重复第一点。每条边最多分成`MAX_DENSIFY_SEGMENTS`段，参见`densify`
+pub fn densify_ring(ring: &[Coordinate], spacing: f64) -> Vec<Coordinate> {
+    densify_ring_with(ring, spacing, &Ellipsoid::WGS84)
+}
+
+/// 沿测地线加密指定椭球下的多边形边界
+///
+/// # 参数
+///
+/// - `ring` - 边界上的点，最后一点与第一点之间的边自动闭合，不需要重复第一点
+/// - `spacing` - 最大间隔，单位为米
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 加密后的边界，同样不重复第一点。每条边最多分成`MAX_DENSIFY_SEGMENTS`段，参见`densify`
+pub fn densify_ring_with(
+    ring: &[Coordinate],
+    spacing: f64,
+    ellipsoid: &Ellipsoid,
+) -> Vec<Coordinate> {
+    let mut coords = Vec::new();
+    for (i, raw) in ring.iter().enumerate() {
+        let target = &ring[(i + 1) % ring.len()];
+        let mut leg = densify_with(raw, target, spacing, ellipsoid);
+        // 终点是下一条边的起点
+        leg.pop();
+        coords.append(&mut leg);
+    }
+    coords
+}
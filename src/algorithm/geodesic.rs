@@ -0,0 +1,945 @@
+//! 椭球上的测地线计算
+//!
+//! 采用Karney（2013）的算法，参照GeographicLib的实现，级数展开到6阶。
+//! 正解和反解对任意输入都能收敛，WGS-84椭球上的误差在15纳米以内，
+//! 近对跖点也不会返回NaN或陷入死循环
+
+use crate::{Coordinate, Ellipsoid};
+use std::f64::consts::PI;
+
+/// 级数展开的阶数
+const ORDER: usize = 6;
+/// A3和C3系数的个数
+const N_C3X: usize = 15;
+
+const TINY: f64 = 1.4916681462400413e-154; // sqrt(f64::MIN_POSITIVE)
+const TOL0: f64 = f64::EPSILON;
+const TOL1: f64 = 200.0 * TOL0;
+const TOL2: f64 = 1.4901161193847656e-8; // sqrt(f64::EPSILON)
+const TOLB: f64 = TOL0;
+const XTHRESH: f64 = 1000.0 * TOL2;
+/// 牛顿迭代的最大次数，之后改用二分法
+const MAXIT1: u32 = 20;
+/// 迭代次数上限，二分法在此之前一定能达到双精度
+const MAXIT2: u32 = MAXIT1 + f64::MANTISSA_DIGITS + 10;
+
+/// 测地线反解的结果
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct GeodesicInverse {
+    /// 距离，单位为米
+    pub distance: f64,
+    /// 起点处的方位角，单位为度，范围[-180, 180]
+    pub initial_azimuth: f64,
+    /// 终点处的方位角（沿测地线前进的方向），单位为度，范围[-180, 180]
+    pub final_azimuth: f64,
+    /// 约化长度m12，单位为米
+    pub reduced_length: f64,
+    /// 测地线尺度M12（终点相对起点）和M21（起点相对终点），无量纲
+    pub geodesic_scale: (f64, f64),
+}
+
+/// 测地线正解的结果
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct GeodesicDirect {
+    /// 终点坐标，经度在(-180, 180]范围内
+    pub coord: Coordinate,
+    /// 终点处的方位角（沿测地线前进的方向），单位为度，范围[-180, 180]
+    pub final_azimuth: f64,
+    /// 约化长度m12，单位为米
+    pub reduced_length: f64,
+    /// 测地线尺度M12（终点相对起点）和M21（起点相对终点），无量纲
+    pub geodesic_scale: (f64, f64),
+}
+
+/// 指定椭球上的测地线计算
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Geodesic {
+    a: f64,
+    f: f64,
+    f1: f64,
+    ep2: f64,
+    n: f64,
+    b: f64,
+    etol2: f64,
+    a3x: [f64; ORDER],
+    c3x: [f64; N_C3X],
+}
+
+impl Default for Geodesic {
+    /// WGS-84椭球
+    fn default() -> Self {
+        Geodesic::new(&Ellipsoid::WGS84)
+    }
+}
+
+fn sq(x: f64) -> f64 {
+    x * x
+}
+
+/// 求和并给出舍入误差
+fn sum(u: f64, v: f64) -> (f64, f64) {
+    let s = u + v;
+    let up = s - v;
+    let vpp = s - up;
+    let t = if s != 0.0 {
+        0.0 - ((up - u) + (vpp - v))
+    } else {
+        s
+    };
+    (s, t)
+}
+
+/// 多项式求值，系数从高次到低次排列
+fn polyval(coeff: &[f64], x: f64) -> f64 {
+    coeff.iter().fold(0.0, |y, &c| y * x + c)
+}
+
+fn norm2(sinx: f64, cosx: f64) -> (f64, f64) {
+    let r = sinx.hypot(cosx);
+    (sinx / r, cosx / r)
+}
+
+/// 角度规范化到(-180, 180]
+fn ang_normalize(x: f64) -> f64 {
+    let x = x % 360.0;
+    if x <= -180.0 {
+        x + 360.0
+    } else if x <= 180.0 {
+        x
+    } else {
+        x - 360.0
+    }
+}
+
+/// 精确计算`y - x`并规范化到(-180, 180]，同时给出舍入误差
+fn ang_diff(x: f64, y: f64) -> (f64, f64) {
+    let (d, t) = sum(ang_normalize(-x), ang_normalize(y));
+    let d = ang_normalize(d);
+    sum(if d == 180.0 && t > 0.0 { -180.0 } else { d }, t)
+}
+
+/// 将很小的角度舍入到1/16度的整数倍附近，避免接近0时的病态情况
+fn ang_round(x: f64) -> f64 {
+    const Z: f64 = 1.0 / 16.0;
+    let y = x.abs();
+    let w = Z - y;
+    let y = if w > 0.0 { Z - w } else { y };
+    y.copysign(x)
+}
+
+/// 角度的正弦和余弦，对90°的整数倍结果精确
+fn sincosd(x: f64) -> (f64, f64) {
+    let r = x % 360.0;
+    let q = (r / 90.0 + 0.5).floor();
+    let r = (r - 90.0 * q).to_radians();
+    let (s, c) = r.sin_cos();
+    let (sinx, cosx) = match (q as i32).rem_euclid(4) {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    let sinx = if sinx == 0.0 { sinx.copysign(x) } else { sinx };
+    (sinx, cosx + 0.0)
+}
+
+/// 以度为单位的`atan2`，对90°的整数倍结果精确
+fn atan2d(y: f64, x: f64) -> f64 {
+    let (mut y, mut x, mut q) = (y, x, 0);
+    if y.abs() > x.abs() {
+        std::mem::swap(&mut x, &mut y);
+        q = 2;
+    }
+    if x.is_sign_negative() {
+        x = -x;
+        q += 1;
+    }
+    let ang = y.atan2(x).to_degrees();
+    match q {
+        1 => 180f64.copysign(y) - ang,
+        2 => 90.0 - ang,
+        3 => -90.0 + ang,
+        _ => ang,
+    }
+}
+
+/// 正弦（`sinp`为真）或余弦级数求和，`c[1..=n]`为系数
+fn sin_cos_series(sinp: bool, sinx: f64, cosx: f64, c: &[f64], n: usize) -> f64 {
+    let mut k = n + sinp as usize;
+    let ar = 2.0 * (cosx - sinx) * (cosx + sinx);
+    let mut y0 = if n & 1 == 1 {
+        k -= 1;
+        c[k]
+    } else {
+        0.0
+    };
+    let mut y1 = 0.0;
+    for _ in 0..n / 2 {
+        k -= 1;
+        y1 = ar * y0 - y1 + c[k];
+        k -= 1;
+        y0 = ar * y1 - y0 + c[k];
+    }
+    if sinp {
+        2.0 * sinx * cosx * y0
+    } else {
+        cosx * (y0 - y1)
+    }
+}
+
+/// (1 - eps) * A1 - 1
+fn a1m1f(eps: f64) -> f64 {
+    const COEFF: [f64; 5] = [1.0, 4.0, 64.0, 0.0, 256.0];
+    let t = polyval(&COEFF[..4], sq(eps)) / COEFF[4];
+    (t + eps) / (1.0 - eps)
+}
+
+/// 按GeographicLib的格式从系数表计算`c[1..=ORDER]`
+fn series_coeffs(coeff: &[f64], eps: f64, c: &mut [f64; ORDER + 1]) {
+    let eps2 = sq(eps);
+    let mut d = eps;
+    let mut o = 0;
+    for (l, c) in c.iter_mut().enumerate().skip(1) {
+        let m = (ORDER - l) / 2;
+        *c = d * polyval(&coeff[o..=o + m], eps2) / coeff[o + m + 1];
+        o += m + 2;
+        d *= eps;
+    }
+}
+
+fn c1f(eps: f64, c: &mut [f64; ORDER + 1]) {
+    const COEFF: [f64; 18] = [
+        -1.0, 6.0, -16.0, 32.0, //
+        -9.0, 64.0, -128.0, 2048.0, //
+        9.0, -16.0, 768.0, //
+        3.0, -5.0, 512.0, //
+        -7.0, 1280.0, //
+        -7.0, 2048.0,
+    ];
+    series_coeffs(&COEFF, eps, c);
+}
+
+fn c1pf(eps: f64, c: &mut [f64; ORDER + 1]) {
+    const COEFF: [f64; 18] = [
+        205.0, -432.0, 768.0, 1536.0, //
+        4005.0, -4736.0, 3840.0, 12288.0, //
+        -225.0, 116.0, 384.0, //
+        -7173.0, 2695.0, 7680.0, //
+        3467.0, 7680.0, //
+        38081.0, 61440.0,
+    ];
+    series_coeffs(&COEFF, eps, c);
+}
+
+/// (1 + eps) * A2 - 1
+fn a2m1f(eps: f64) -> f64 {
+    const COEFF: [f64; 5] = [-11.0, -28.0, -192.0, 0.0, 256.0];
+    let t = polyval(&COEFF[..4], sq(eps)) / COEFF[4];
+    (t - eps) / (1.0 + eps)
+}
+
+fn c2f(eps: f64, c: &mut [f64; ORDER + 1]) {
+    const COEFF: [f64; 18] = [
+        1.0, 2.0, 16.0, 32.0, //
+        35.0, 64.0, 384.0, 2048.0, //
+        15.0, 80.0, 768.0, //
+        7.0, 35.0, 512.0, //
+        63.0, 1280.0, //
+        77.0, 2048.0,
+    ];
+    series_coeffs(&COEFF, eps, c);
+}
+
+/// 求解`k^4 + 2 k^3 - (x^2 + y^2 - 1) k^2 - 2 y^2 k - y^2 = 0`的正根
+fn astroid(x: f64, y: f64) -> f64 {
+    let p = sq(x);
+    let q = sq(y);
+    let r = (p + q - 1.0) / 6.0;
+    if q == 0.0 && r <= 0.0 {
+        return 0.0;
+    }
+    let s = p * q / 4.0;
+    let r2 = sq(r);
+    let r3 = r * r2;
+    let disc = s * (s + 2.0 * r3);
+    let mut u = r;
+    if disc >= 0.0 {
+        let mut t3 = s + r3;
+        t3 += if t3 < 0.0 { -disc.sqrt() } else { disc.sqrt() };
+        let t = t3.cbrt();
+        u += t + if t != 0.0 { r2 / t } else { 0.0 };
+    } else {
+        let ang = (-disc).sqrt().atan2(-(s + r3));
+        u += 2.0 * r * (ang / 3.0).cos();
+    }
+    let v = (sq(u) + q).sqrt();
+    let uv = if u < 0.0 { q / (v - u) } else { u + v };
+    let w = (uv - q) / (2.0 * v);
+    uv / ((uv + sq(w)).sqrt() + w)
+}
+
+/// `Geodesic::lengths`的结果，长度以短半轴为单位
+struct Lengths {
+    s12b: f64,
+    m12b: f64,
+    m0: f64,
+    scale12: f64,
+    scale21: f64,
+}
+
+/// `Geodesic::lambda12`的结果
+struct Lambda12 {
+    lam12: f64,
+    salp2: f64,
+    calp2: f64,
+    sig12: f64,
+    ssig1: f64,
+    csig1: f64,
+    ssig2: f64,
+    csig2: f64,
+    eps: f64,
+    dlam12: f64,
+}
+
+impl Geodesic {
+    /// 创建测地线计算
+    ///
+    /// # 参数
+    ///
+    /// - `ellipsoid` - 椭球，扁率的绝对值应小于0.2左右
+    pub fn new(ellipsoid: &Ellipsoid) -> Self {
+        let (a, f) = (ellipsoid.a, ellipsoid.f);
+        let f1 = 1.0 - f;
+        let e2 = f * (2.0 - f);
+        let n = f / (2.0 - f);
+        let mut geodesic = Geodesic {
+            a,
+            f,
+            f1,
+            ep2: e2 / sq(f1),
+            n,
+            b: a * f1,
+            etol2: 0.1 * TOL2 / (f.abs().max(0.001) * (1.0 - f / 2.0).min(1.0) / 2.0).sqrt(),
+            a3x: [0.0; ORDER],
+            c3x: [0.0; N_C3X],
+        };
+        geodesic.a3coeff();
+        geodesic.c3coeff();
+        geodesic
+    }
+
+    fn a3coeff(&mut self) {
+        const COEFF: [f64; 18] = [
+            -3.0, 128.0, //
+            -2.0, -3.0, 64.0, //
+            -1.0, -3.0, -1.0, 16.0, //
+            3.0, -1.0, -2.0, 8.0, //
+            1.0, -1.0, 2.0, //
+            1.0, 1.0,
+        ];
+        let mut o = 0;
+        for (k, j) in (0..ORDER).rev().enumerate() {
+            let m = (ORDER - j - 1).min(j);
+            self.a3x[k] = polyval(&COEFF[o..=o + m], self.n) / COEFF[o + m + 1];
+            o += m + 2;
+        }
+    }
+
+    fn c3coeff(&mut self) {
+        const COEFF: [f64; 45] = [
+            3.0, 128.0, //
+            2.0, 5.0, 128.0, //
+            -1.0, 3.0, 3.0, 64.0, //
+            -1.0, 0.0, 1.0, 8.0, //
+            -1.0, 1.0, 4.0, //
+            5.0, 256.0, //
+            1.0, 3.0, 128.0, //
+            -3.0, -2.0, 3.0, 64.0, //
+            1.0, -3.0, 2.0, 32.0, //
+            7.0, 512.0, //
+            -10.0, 9.0, 384.0, //
+            5.0, -9.0, 5.0, 192.0, //
+            7.0, 512.0, //
+            -14.0, 7.0, 512.0, //
+            21.0, 2560.0,
+        ];
+        let (mut o, mut k) = (0, 0);
+        for l in 1..ORDER {
+            for j in (l..ORDER).rev() {
+                let m = (ORDER - j - 1).min(j);
+                self.c3x[k] = polyval(&COEFF[o..=o + m], self.n) / COEFF[o + m + 1];
+                k += 1;
+                o += m + 2;
+            }
+        }
+    }
+
+    fn a3f(&self, eps: f64) -> f64 {
+        polyval(&self.a3x, eps)
+    }
+
+    fn c3f(&self, eps: f64, c: &mut [f64; ORDER + 1]) {
+        let mut mult = 1.0;
+        let mut o = 0;
+        for (l, c) in c.iter_mut().enumerate().take(ORDER).skip(1) {
+            let m = ORDER - l - 1;
+            mult *= eps;
+            *c = mult * polyval(&self.c3x[o..=o + m], eps);
+            o += m + 1;
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn lengths(
+        &self,
+        eps: f64,
+        sig12: f64,
+        (ssig1, csig1, dn1): (f64, f64, f64),
+        (ssig2, csig2, dn2): (f64, f64, f64),
+        cbet1: f64,
+        cbet2: f64,
+    ) -> Lengths {
+        let mut ca = [0.0; ORDER + 1];
+        let mut cb = [0.0; ORDER + 1];
+        let a1 = a1m1f(eps);
+        c1f(eps, &mut ca);
+        let a2 = a2m1f(eps);
+        c2f(eps, &mut cb);
+        let m0 = a1 - a2;
+        let (a1, a2) = (1.0 + a1, 1.0 + a2);
+
+        let b1 = sin_cos_series(true, ssig2, csig2, &ca, ORDER)
+            - sin_cos_series(true, ssig1, csig1, &ca, ORDER);
+        let b2 = sin_cos_series(true, ssig2, csig2, &cb, ORDER)
+            - sin_cos_series(true, ssig1, csig1, &cb, ORDER);
+        let j12 = m0 * sig12 + (a1 * b1 - a2 * b2);
+
+        let csig12 = csig1 * csig2 + ssig1 * ssig2;
+        let t = self.ep2 * (cbet1 - cbet2) * (cbet1 + cbet2) / (dn1 + dn2);
+        Lengths {
+            s12b: a1 * (sig12 + b1),
+            m12b: dn2 * (csig1 * ssig2) - dn1 * (ssig1 * csig2) - csig1 * csig2 * j12,
+            m0,
+            scale12: csig12 + (t * ssig2 - csig2 * j12) * ssig1 / dn1,
+            scale21: csig12 - (t * ssig1 - csig1 * j12) * ssig2 / dn2,
+        }
+    }
+
+    /// 牛顿迭代的初始值，短距离时直接给出结果（返回非负的`sig12`）
+    #[allow(clippy::too_many_arguments)]
+    fn inverse_start(
+        &self,
+        (sbet1, cbet1, dn1): (f64, f64, f64),
+        (sbet2, cbet2, dn2): (f64, f64, f64),
+        lam12: f64,
+        slam12: f64,
+        clam12: f64,
+    ) -> (f64, f64, f64, f64, f64, f64) {
+        let (mut salp2, mut calp2, mut dnm) = (0.0, 0.0, 0.0);
+        let mut sig12 = -1.0;
+        let sbet12 = sbet2 * cbet1 - cbet2 * sbet1;
+        let cbet12 = cbet2 * cbet1 + sbet2 * sbet1;
+        let sbet12a = sbet2 * cbet1 + cbet2 * sbet1;
+        let shortline = cbet12 >= 0.0 && sbet12 < 0.5 && cbet2 * lam12 < 0.5;
+
+        let (mut somg12, mut comg12) = if shortline {
+            let mut sbetm2 = sq(sbet1 + sbet2);
+            sbetm2 /= sbetm2 + sq(cbet1 + cbet2);
+            dnm = (1.0 + self.ep2 * sbetm2).sqrt();
+            (lam12 / (self.f1 * dnm)).sin_cos()
+        } else {
+            (slam12, clam12)
+        };
+
+        let mut salp1 = cbet2 * somg12;
+        let mut calp1 = if comg12 >= 0.0 {
+            sbet12 + cbet2 * sbet1 * sq(somg12) / (1.0 + comg12)
+        } else {
+            sbet12a - cbet2 * sbet1 * sq(somg12) / (1.0 - comg12)
+        };
+
+        let ssig12 = salp1.hypot(calp1);
+        let csig12 = sbet1 * sbet2 + cbet1 * cbet2 * comg12;
+
+        if shortline && ssig12 < self.etol2 {
+            salp2 = cbet1 * somg12;
+            calp2 = sbet12
+                - cbet1
+                    * sbet2
+                    * if comg12 >= 0.0 {
+                        sq(somg12) / (1.0 + comg12)
+                    } else {
+                        1.0 - comg12
+                    };
+            (salp2, calp2) = norm2(salp2, calp2);
+            sig12 = ssig12.atan2(csig12);
+        } else if self.n.abs() > 0.1
+            || csig12 >= 0.0
+            || ssig12 >= 6.0 * self.n.abs() * PI * sq(cbet1)
+        {
+            // 球面近似已经足够
+        } else {
+            // 近对跖点，按Karney（2013）第7节求解
+            let lam12x = (-slam12).atan2(-clam12);
+            let (x, y, lamscale) = if self.f >= 0.0 {
+                let k2 = sq(sbet1) * self.ep2;
+                let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+                let lamscale = self.f * cbet1 * self.a3f(eps) * PI;
+                let betscale = lamscale * cbet1;
+                (lam12x / lamscale, sbet12a / betscale, lamscale)
+            } else {
+                let cbet12a = cbet2 * cbet1 - sbet2 * sbet1;
+                let bet12a = sbet12a.atan2(cbet12a);
+                let lengths = self.lengths(
+                    self.n,
+                    PI + bet12a,
+                    (sbet1, -cbet1, dn1),
+                    (sbet2, cbet2, dn2),
+                    cbet1,
+                    cbet2,
+                );
+                let x = -1.0 + lengths.m12b / (cbet1 * cbet2 * lengths.m0 * PI);
+                let betscale = if x < -0.01 {
+                    sbet12a / x
+                } else {
+                    -self.f * sq(cbet1) * PI
+                };
+                let lamscale = betscale / cbet1;
+                (x, lam12x / lamscale, lamscale)
+            };
+
+            if y > -TOL1 && x > -1.0 - XTHRESH {
+                if self.f >= 0.0 {
+                    salp1 = (-x).min(1.0);
+                    calp1 = -(1.0 - sq(salp1)).sqrt();
+                } else {
+                    calp1 = x.max(if x > -TOL1 { 0.0 } else { -1.0 });
+                    salp1 = (1.0 - sq(calp1)).sqrt();
+                }
+            } else {
+                let k = astroid(x, y);
+                let omg12a = lamscale
+                    * if self.f >= 0.0 {
+                        -x * k / (1.0 + k)
+                    } else {
+                        -y * (1.0 + k) / k
+                    };
+                (somg12, comg12) = omg12a.sin_cos();
+                comg12 = -comg12;
+                salp1 = cbet2 * somg12;
+                calp1 = sbet12a - cbet2 * sbet1 * sq(somg12) / (1.0 - comg12);
+            }
+        }
+
+        if salp1 > 0.0 {
+            (salp1, calp1) = norm2(salp1, calp1);
+        } else {
+            (salp1, calp1) = (1.0, 0.0);
+        }
+        (sig12, salp1, calp1, salp2, calp2, dnm)
+    }
+
+    /// 给定起点方位角时，终点经度与目标经度之差及其导数
+    #[allow(clippy::too_many_arguments)]
+    fn lambda12(
+        &self,
+        (sbet1, cbet1, dn1): (f64, f64, f64),
+        (sbet2, cbet2, dn2): (f64, f64, f64),
+        salp1: f64,
+        calp1: f64,
+        slam120: f64,
+        clam120: f64,
+        diffp: bool,
+    ) -> Lambda12 {
+        let calp1 = if sbet1 == 0.0 && calp1 == 0.0 {
+            -TINY
+        } else {
+            calp1
+        };
+
+        let salp0 = salp1 * cbet1;
+        let calp0 = calp1.hypot(salp1 * sbet1);
+
+        let somg1 = salp0 * sbet1;
+        let comg1 = calp1 * cbet1;
+        let (ssig1, csig1) = norm2(sbet1, comg1);
+
+        let salp2 = if cbet2 != cbet1 { salp0 / cbet2 } else { salp1 };
+        let calp2 = if cbet2 != cbet1 || sbet2.abs() != -sbet1 {
+            (sq(calp1 * cbet1)
+                + if cbet1 < -sbet1 {
+                    (cbet2 - cbet1) * (cbet1 + cbet2)
+                } else {
+                    (sbet1 - sbet2) * (sbet1 + sbet2)
+                })
+            .sqrt()
+                / cbet2
+        } else {
+            calp1.abs()
+        };
+        let somg2 = salp0 * sbet2;
+        let comg2 = calp2 * cbet2;
+        let (ssig2, csig2) = norm2(sbet2, comg2);
+
+        let sig12 = (csig1 * ssig2 - ssig1 * csig2)
+            .max(0.0)
+            .atan2(csig1 * csig2 + ssig1 * ssig2);
+
+        let somg12 = (comg1 * somg2 - somg1 * comg2).max(0.0);
+        let comg12 = comg1 * comg2 + somg1 * somg2;
+        let eta = (somg12 * clam120 - comg12 * slam120).atan2(comg12 * clam120 + somg12 * slam120);
+        let k2 = sq(calp0) * self.ep2;
+        let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+        let mut c3a = [0.0; ORDER + 1];
+        self.c3f(eps, &mut c3a);
+        let b312 = sin_cos_series(true, ssig2, csig2, &c3a, ORDER - 1)
+            - sin_cos_series(true, ssig1, csig1, &c3a, ORDER - 1);
+        let domg12 = -self.f * self.a3f(eps) * salp0 * (sig12 + b312);
+
+        let mut dlam12 = 0.0;
+        if diffp {
+            if calp2 == 0.0 {
+                dlam12 = -2.0 * self.f1 * dn1 / sbet1;
+            } else {
+                let lengths = self.lengths(
+                    eps,
+                    sig12,
+                    (ssig1, csig1, dn1),
+                    (ssig2, csig2, dn2),
+                    cbet1,
+                    cbet2,
+                );
+                dlam12 = lengths.m12b * self.f1 / (calp2 * cbet2);
+            }
+        }
+
+        Lambda12 {
+            lam12: eta + domg12,
+            salp2,
+            calp2,
+            sig12,
+            ssig1,
+            csig1,
+            ssig2,
+            csig2,
+            eps,
+            dlam12,
+        }
+    }
+
+    /// 测地线反解
+    ///
+    /// # 参数
+    ///
+    /// - `raw` - 起点
+    /// - `target` - 终点
+    ///
+    /// # 返回值
+    ///
+    /// 距离和两端的方位角，纬度超出[-90, 90]时结果为NaN
+    pub fn inverse(&self, raw: &Coordinate, target: &Coordinate) -> GeodesicInverse {
+        let (lon12, lon12s) = ang_diff(raw.lon, target.lon);
+        // 使经度差为正
+        let mut lonsign = if lon12 >= 0.0 { 1.0 } else { -1.0 };
+        // 非常接近同一子午线时视为同一子午线
+        let lon12 = lonsign * ang_round(lon12);
+        let lon12s = ang_round((180.0 - lon12) - lonsign * lon12s);
+        let lam12 = lon12.to_radians();
+        let (slam12, clam12) = if lon12 > 90.0 {
+            let (s, c) = sincosd(lon12s);
+            (s, -c)
+        } else {
+            sincosd(lon12)
+        };
+
+        // 非常接近赤道时视为赤道
+        let lat_fix = |lat: f64| if lat.abs() > 90.0 { f64::NAN } else { lat };
+        let mut lat1 = ang_round(lat_fix(raw.lat));
+        let mut lat2 = ang_round(lat_fix(target.lat));
+        // 交换两点使起点纬度的绝对值较大
+        let swapp = if lat1.abs() < lat2.abs() { -1.0 } else { 1.0 };
+        if swapp < 0.0 {
+            lonsign = -lonsign;
+            std::mem::swap(&mut lat1, &mut lat2);
+        }
+        // 使起点纬度不大于0
+        let latsign = if lat1 < 0.0 { 1.0 } else { -1.0 };
+        lat1 *= latsign;
+        lat2 *= latsign;
+
+        let reduced = |lat: f64| {
+            let (s, c) = sincosd(lat);
+            let (s, c) = norm2(s * self.f1, c);
+            (s, c.max(TINY))
+        };
+        let (sbet1, cbet1) = reduced(lat1);
+        let (mut sbet2, mut cbet2) = reduced(lat2);
+
+        if cbet1 < -sbet1 {
+            if cbet2 == cbet1 {
+                sbet2 = if sbet2 < 0.0 { sbet1 } else { -sbet1 };
+            }
+        } else if sbet2.abs() == -sbet1 {
+            cbet2 = cbet1;
+        }
+
+        let dn1 = (1.0 + self.ep2 * sq(sbet1)).sqrt();
+        let dn2 = (1.0 + self.ep2 * sq(sbet2)).sqrt();
+        let p1 = (sbet1, cbet1, dn1);
+        let p2 = (sbet2, cbet2, dn2);
+
+        let (mut salp1, mut calp1, mut salp2, mut calp2) = (0.0, 0.0, 0.0, 0.0);
+        let (mut s12x, mut m12x) = (0.0, 0.0);
+        let (mut scale12, mut scale21) = (1.0, 1.0);
+
+        let mut meridian = lat1 == -90.0 || slam12 == 0.0;
+        if meridian {
+            // 沿子午线
+            (salp1, calp1) = (slam12, clam12);
+            (salp2, calp2) = (0.0, 1.0);
+            let (ssig1, csig1) = (sbet1, calp1 * cbet1);
+            let (ssig2, csig2) = (sbet2, calp2 * cbet2);
+            let sig12 = (csig1 * ssig2 - ssig1 * csig2)
+                .max(0.0)
+                .atan2(csig1 * csig2 + ssig1 * ssig2);
+            let lengths = self.lengths(
+                self.n,
+                sig12,
+                (ssig1, csig1, dn1),
+                (ssig2, csig2, dn2),
+                cbet1,
+                cbet2,
+            );
+            (s12x, m12x) = (lengths.s12b, lengths.m12b);
+            (scale12, scale21) = (lengths.scale12, lengths.scale21);
+            if sig12 < 1.0 || m12x >= 0.0 {
+                // 避免极短距离时出现负值
+                if sig12 < 3.0 * TINY || (sig12 < TOL0 && (s12x < 0.0 || m12x < 0.0)) {
+                    m12x = 0.0;
+                    s12x = 0.0;
+                }
+                m12x *= self.b;
+                s12x *= self.b;
+            } else {
+                // 扁长椭球上接近对跖点，m12 < 0
+                meridian = false;
+            }
+        }
+
+        if !meridian && sbet1 == 0.0 && (self.f <= 0.0 || lon12s >= self.f * 180.0) {
+            // 沿赤道
+            (salp1, calp1, salp2, calp2) = (1.0, 0.0, 1.0, 0.0);
+            s12x = self.a * lam12;
+            let sig12 = lam12 / self.f1;
+            m12x = self.b * sig12.sin();
+            scale12 = sig12.cos();
+            scale21 = scale12;
+        } else if !meridian {
+            let (sig12, s1, c1, s2, c2, dnm) = self.inverse_start(p1, p2, lam12, slam12, clam12);
+            (salp1, calp1) = (s1, c1);
+
+            if sig12 >= 0.0 {
+                // 短距离，InverseStart已给出结果
+                (salp2, calp2) = (s2, c2);
+                s12x = sig12 * self.b * dnm;
+                m12x = sq(dnm) * self.b * (sig12 / dnm).sin();
+                scale12 = (sig12 / dnm).cos();
+                scale21 = scale12;
+            } else {
+                // 牛顿迭代，失败时在区间内二分
+                let (mut salp1a, mut calp1a, mut salp1b, mut calp1b) = (TINY, 1.0, TINY, -1.0);
+                let mut tripn = false;
+                let mut tripb = false;
+                let mut numit = 0;
+                let result = loop {
+                    let result =
+                        self.lambda12(p1, p2, salp1, calp1, slam12, clam12, numit < MAXIT1);
+                    let (v, dv) = (result.lam12, result.dlam12);
+                    if tripb
+                        || v.is_nan()
+                        || v.abs() < if tripn { 8.0 } else { 1.0 } * TOL0
+                        || numit == MAXIT2
+                    {
+                        break result;
+                    }
+                    if v > 0.0 && (numit > MAXIT1 || calp1 / salp1 > calp1b / salp1b) {
+                        (salp1b, calp1b) = (salp1, calp1);
+                    } else if v < 0.0 && (numit > MAXIT1 || calp1 / salp1 < calp1a / salp1a) {
+                        (salp1a, calp1a) = (salp1, calp1);
+                    }
+                    numit += 1;
+                    if numit <= MAXIT1 && dv > 0.0 {
+                        let dalp1 = -v / dv;
+                        if dalp1.abs() < PI {
+                            let (sdalp1, cdalp1) = dalp1.sin_cos();
+                            let nsalp1 = salp1 * cdalp1 + calp1 * sdalp1;
+                            if nsalp1 > 0.0 {
+                                calp1 = calp1 * cdalp1 - salp1 * sdalp1;
+                                salp1 = nsalp1;
+                                (salp1, calp1) = norm2(salp1, calp1);
+                                tripn = v.abs() <= 16.0 * TOL0;
+                                continue;
+                            }
+                        }
+                    }
+                    salp1 = (salp1a + salp1b) / 2.0;
+                    calp1 = (calp1a + calp1b) / 2.0;
+                    (salp1, calp1) = norm2(salp1, calp1);
+                    tripn = false;
+                    tripb = (salp1a - salp1).abs() + (calp1a - calp1) < TOLB
+                        || (salp1 - salp1b).abs() + (calp1 - calp1b) < TOLB;
+                };
+                (salp2, calp2) = (result.salp2, result.calp2);
+                let lengths = self.lengths(
+                    result.eps,
+                    result.sig12,
+                    (result.ssig1, result.csig1, dn1),
+                    (result.ssig2, result.csig2, dn2),
+                    cbet1,
+                    cbet2,
+                );
+                s12x = lengths.s12b * self.b;
+                m12x = lengths.m12b * self.b;
+                (scale12, scale21) = (lengths.scale12, lengths.scale21);
+            }
+        }
+
+        // 还原交换和对称变换
+        if swapp < 0.0 {
+            std::mem::swap(&mut salp1, &mut salp2);
+            std::mem::swap(&mut calp1, &mut calp2);
+            std::mem::swap(&mut scale12, &mut scale21);
+        }
+        salp1 *= swapp * lonsign;
+        calp1 *= swapp * latsign;
+        salp2 *= swapp * lonsign;
+        calp2 *= swapp * latsign;
+
+        GeodesicInverse {
+            distance: 0.0 + s12x,
+            initial_azimuth: atan2d(salp1, calp1),
+            final_azimuth: atan2d(salp2, calp2),
+            reduced_length: 0.0 + m12x,
+            geodesic_scale: (scale12, scale21),
+        }
+    }
+
+    /// 测地线正解
+    ///
+    /// # 参数
+    ///
+    /// - `raw` - 起点
+    /// - `azimuth` - 起点处的方位角，单位为度
+    /// - `distance` - 距离，单位为米，可以为负数
+    ///
+    /// # 返回值
+    ///
+    /// 终点坐标和终点处的方位角，纬度超出[-90, 90]时结果为NaN
+    pub fn direct(&self, raw: &Coordinate, azimuth: f64, distance: f64) -> GeodesicDirect {
+        let lat1 = if raw.lat.abs() > 90.0 {
+            f64::NAN
+        } else {
+            raw.lat
+        };
+        let (salp1, calp1) = sincosd(ang_round(ang_normalize(azimuth)));
+
+        let (sbet1, cbet1) = sincosd(ang_round(lat1));
+        let (sbet1, cbet1) = norm2(sbet1 * self.f1, cbet1);
+        let cbet1 = cbet1.max(TINY);
+        let dn1 = (1.0 + self.ep2 * sq(sbet1)).sqrt();
+
+        let salp0 = salp1 * cbet1;
+        let calp0 = calp1.hypot(salp1 * sbet1);
+        let somg1 = salp0 * sbet1;
+        let comg1 = if sbet1 != 0.0 || calp1 != 0.0 {
+            cbet1 * calp1
+        } else {
+            1.0
+        };
+        let (ssig1, csig1) = norm2(sbet1, comg1);
+
+        let k2 = sq(calp0) * self.ep2;
+        let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+
+        let a1m1 = a1m1f(eps);
+        let mut c1a = [0.0; ORDER + 1];
+        c1f(eps, &mut c1a);
+        let b11 = sin_cos_series(true, ssig1, csig1, &c1a, ORDER);
+        let (s, c) = b11.sin_cos();
+        let stau1 = ssig1 * c + csig1 * s;
+        let ctau1 = csig1 * c - ssig1 * s;
+        let mut c1pa = [0.0; ORDER + 1];
+        c1pf(eps, &mut c1pa);
+        let a2m1 = a2m1f(eps);
+        let mut c2a = [0.0; ORDER + 1];
+        c2f(eps, &mut c2a);
+        let b21 = sin_cos_series(true, ssig1, csig1, &c2a, ORDER);
+        let mut c3a = [0.0; ORDER + 1];
+        self.c3f(eps, &mut c3a);
+        let a3c = -self.f * salp0 * self.a3f(eps);
+        let b31 = sin_cos_series(true, ssig1, csig1, &c3a, ORDER - 1);
+
+        // 由距离求球面上的弧长
+        let tau12 = distance / (self.b * (1.0 + a1m1));
+        let (s, c) = tau12.sin_cos();
+        let b12 = -sin_cos_series(
+            true,
+            stau1 * c + ctau1 * s,
+            ctau1 * c - stau1 * s,
+            &c1pa,
+            ORDER,
+        );
+        let mut sig12 = tau12 - (b12 - b11);
+        let (mut ssig12, mut csig12) = sig12.sin_cos();
+        if self.f.abs() > 0.01 {
+            // 扁率较大时用一次牛顿迭代修正
+            let ssig2 = ssig1 * csig12 + csig1 * ssig12;
+            let csig2 = csig1 * csig12 - ssig1 * ssig12;
+            let b12 = sin_cos_series(true, ssig2, csig2, &c1a, ORDER);
+            let serr = (1.0 + a1m1) * (sig12 + (b12 - b11)) - distance / self.b;
+            sig12 -= serr / (1.0 + k2 * sq(ssig2)).sqrt();
+            (ssig12, csig12) = sig12.sin_cos();
+        }
+
+        let ssig2 = ssig1 * csig12 + csig1 * ssig12;
+        let mut csig2 = csig1 * csig12 - ssig1 * ssig12;
+        let dn2 = (1.0 + k2 * sq(ssig2)).sqrt();
+        let b12 = sin_cos_series(true, ssig2, csig2, &c1a, ORDER);
+        let ab1 = (1.0 + a1m1) * (b12 - b11);
+        let sbet2 = calp0 * ssig2;
+        let mut cbet2 = salp0.hypot(calp0 * csig2);
+        if cbet2 == 0.0 {
+            cbet2 = TINY;
+            csig2 = TINY;
+        }
+        let salp2 = salp0;
+        let calp2 = calp0 * csig2;
+
+        let somg2 = salp0 * ssig2;
+        let comg2 = csig2;
+        let omg12 = (somg2 * comg1 - comg2 * somg1).atan2(comg2 * comg1 + somg2 * somg1);
+        let lam12 =
+            omg12 + a3c * (sig12 + (sin_cos_series(true, ssig2, csig2, &c3a, ORDER - 1) - b31));
+        let lon12 = lam12.to_degrees();
+        let lon2 = ang_normalize(ang_normalize(raw.lon) + ang_normalize(lon12));
+        let lat2 = atan2d(sbet2, self.f1 * cbet2);
+
+        let b22 = sin_cos_series(true, ssig2, csig2, &c2a, ORDER);
+        let ab2 = (1.0 + a2m1) * (b22 - b21);
+        let j12 = (a1m1 - a2m1) * sig12 + (ab1 - ab2);
+        let m12 = self.b * ((dn2 * (csig1 * ssig2) - dn1 * (ssig1 * csig2)) - csig1 * csig2 * j12);
+        let t = k2 * (ssig2 - ssig1) * (ssig2 + ssig1) / (dn1 + dn2);
+        let scale12 = csig12 + (t * ssig2 - csig2 * j12) * ssig1 / dn1;
+        let scale21 = csig12 - (t * ssig1 - csig1 * j12) * ssig2 / dn2;
+
+        GeodesicDirect {
+            coord: Coordinate {
+                lat: lat2,
+                lon: lon2,
+            },
+            final_azimuth: atan2d(salp2, calp2),
+            reduced_length: m12,
+            geodesic_scale: (scale12, scale21),
+        }
+    }
+}
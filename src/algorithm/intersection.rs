@@ -0,0 +1,645 @@
+//! 测地线和距离圆的交点，用于径向线/径向线、径向线/DME、DME/DME定位
+
+use crate::algorithm::bearing::{MagneticBearing, TrueBearing};
+use crate::algorithm::geodesic::Geodesic;
+use crate::coordinate::normalize_lon;
+use crate::{Coordinate, Ellipsoid};
+
+/// 迭代的最大次数
+const MAX_ITERATIONS: usize = 30;
+/// 收敛条件，单位为米
+const TOLERANCE: f64 = 1e-9;
+/// 判断交点是否在射线或线段上时允许的误差，单位为米
+const ENDPOINT_TOLERANCE: f64 = 1e-6;
+/// 两个候选点的距离小于该值时视为同一点（相切），单位为米
+const DUPLICATE_TOLERANCE: f64 = 1e-6;
+
+/// 单位球面上的点，地心直角坐标
+fn unit_vector(coord: &Coordinate) -> [f64; 3] {
+    let (sin_lat, cos_lat) = coord.lat.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = coord.lon.to_radians().sin_cos();
+    [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// 点处沿方位角（度数）的单位方向
+fn direction(coord: &Coordinate, azimuth: f64) -> [f64; 3] {
+    let (sin_lat, cos_lat) = coord.lat.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = coord.lon.to_radians().sin_cos();
+    let (sin_az, cos_az) = azimuth.to_radians().sin_cos();
+    let north = [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat];
+    let east = [-sin_lon, cos_lon, 0.0];
+    [
+        north[0] * cos_az + east[0] * sin_az,
+        north[1] * cos_az + east[1] * sin_az,
+        north[2] * cos_az + east[2] * sin_az,
+    ]
+}
+
+/// 球面上两条大圆的交点，取位于两条射线前方的一个作为初始值
+fn spherical_intersection(
+    raw1: &Coordinate,
+    azimuth1: f64,
+    raw2: &Coordinate,
+    azimuth2: f64,
+) -> Option<Coordinate> {
+    let (p1, p2) = (unit_vector(raw1), unit_vector(raw2));
+    let (d1, d2) = (direction(raw1, azimuth1), direction(raw2, azimuth2));
+    let mut i = cross(cross(p1, d1), cross(p2, d2));
+    let norm = dot(i, i).sqrt();
+    if norm < 1e-12 {
+        // 两条大圆重合
+        return None;
+    }
+    if dot(i, d1) + dot(i, d2) < 0.0 {
+        i = [-i[0], -i[1], -i[2]];
+    }
+    Some(Coordinate {
+        lat: (i[2] / norm).asin().to_degrees(),
+        lon: i[1].atan2(i[0]).to_degrees(),
+    })
+}
+
+/// 两条射线（测地线）的交点
+///
+/// 从球面交点开始，在两条测地线上各取一点，在其中一点处按平面几何修正两点沿测地线的距离，
+/// 直到两点重合。返回交点和交点沿两条测地线到起点的距离
+fn intersect(
+    geodesic: &Geodesic,
+    raw1: &Coordinate,
+    azimuth1: f64,
+    raw2: &Coordinate,
+    azimuth2: f64,
+) -> Option<(Coordinate, f64, f64)> {
+    if geodesic.inverse(raw1, raw2).distance < TOLERANCE {
+        return Some((*raw1, 0.0, 0.0));
+    }
+    let guess = spherical_intersection(raw1, azimuth1, raw2, azimuth2)?;
+    let mut s1 = geodesic.inverse(raw1, &guess).distance;
+    let mut s2 = geodesic.inverse(raw2, &guess).distance;
+
+    for _ in 0..MAX_ITERATIONS {
+        let p1 = geodesic.direct(raw1, azimuth1, s1);
+        let p2 = geodesic.direct(raw2, azimuth2, s2);
+        let between = geodesic.inverse(&p1.coord, &p2.coord);
+        if between.distance < TOLERANCE {
+            return Some((p1.coord, s1, s2));
+        }
+
+        // 在第一点处的切平面内求解，第二条测地线的方向按连接两点的测地线转换
+        let alpha1 = p1.final_azimuth.to_radians();
+        let beta = between.initial_azimuth.to_radians();
+        let gamma = beta + (p2.final_azimuth - between.final_azimuth).to_radians();
+        let det = (gamma - alpha1).sin();
+        if det.abs() < 1e-12 {
+            return None;
+        }
+        s1 += between.distance * (gamma - beta).sin() / det;
+        s2 += between.distance * (alpha1 - beta).sin() / det;
+    }
+    None
+}
+
+fn normalized(coord: Coordinate) -> Coordinate {
+    Coordinate {
+        lat: coord.lat,
+        lon: normalize_lon(coord.lon),
+    }
+}
+
+/// 获取WGS-84模型下从两点出发、沿给定磁方向的两条测地线的交点，例如两条VOR径向线的交叉定位点
+///
+/// # 参数
+///
+/// - `raw1` - 第一个起点
+/// - `bearing1` - 第一个磁方向
+/// - `mag_var1` - 第一个起点处的磁偏角（东正西负）
+/// - `raw2` - 第二个起点
+/// - `bearing2` - 第二个磁方向
+/// - `mag_var2` - 第二个起点处的磁偏角（东正西负）
+///
+/// # 返回值
+///
+/// 位于两个起点前方的交点，经度在[-180, 180)范围内。两条测地线重合、平行或交点在任一起点后方时返回`None`
+pub fn get_radial_intersection(
+    raw1: &Coordinate,
+    bearing1: MagneticBearing,
+    mag_var1: f64,
+    raw2: &Coordinate,
+    bearing2: MagneticBearing,
+    mag_var2: f64,
+) -> Option<Coordinate> {
+    get_radial_intersection_with(
+        raw1,
+        bearing1,
+        mag_var1,
+        raw2,
+        bearing2,
+        mag_var2,
+        &Ellipsoid::WGS84,
+    )
+}
+
+/// 获取指定椭球下从两点出发、沿给定磁方向的两条测地线的交点
+///
+/// # 参数
+///
+/// - `raw1` - 第一个起点
+/// - `bearing1` - 第一个磁方向
+/// - `mag_var1` - 第一个起点处的磁偏角（东正西负）
+/// - `raw2` - 第二个起点
+/// - `bearing2` - 第二个磁方向
+/// - `mag_var2` - 第二个起点处的磁偏角（东正西负）
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 位于两个起点前方的交点，经度在[-180, 180)范围内。两条测地线重合、平行或交点在任一起点后方时返回`None`
+pub fn get_radial_intersection_with(
+    raw1: &Coordinate,
+    bearing1: MagneticBearing,
+    mag_var1: f64,
+    raw2: &Coordinate,
+    bearing2: MagneticBearing,
+    mag_var2: f64,
+    ellipsoid: &Ellipsoid,
+) -> Option<Coordinate> {
+    get_radial_intersection_true_with(
+        raw1,
+        bearing1.to_true(mag_var1),
+        raw2,
+        bearing2.to_true(mag_var2),
+        ellipsoid,
+    )
+}
+
+/// 获取WGS-84模型下从两点出发、沿给定真方向的两条测地线的交点
+///
+/// # 参数
+///
+/// - `raw1` - 第一个起点
+/// - `bearing1` - 第一个真方向
+/// - `raw2` - 第二个起点
+/// - `bearing2` - 第二个真方向
+///
+/// # 返回值
+///
+/// 位于两个起点前方的交点，经度在[-180, 180)范围内。两条测地线重合、平行或交点在任一起点后方时返回`None`
+pub fn get_radial_intersection_true(
+    raw1: &Coordinate,
+    bearing1: TrueBearing,
+    raw2: &Coordinate,
+    bearing2: TrueBearing,
+) -> Option<Coordinate> {
+    get_radial_intersection_true_with(raw1, bearing1, raw2, bearing2, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下从两点出发、沿给定真方向的两条测地线的交点
+///
+/// # 参数
+///
+/// - `raw1` - 第一个起点
+/// - `bearing1` - 第一个真方向
+/// - `raw2` - 第二个起点
+/// - `bearing2` - 第二个真方向
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 位于两个起点前方的交点，经度在[-180, 180)范围内。两条测地线重合、平行或交点在任一起点后方时返回`None`
+pub fn get_radial_intersection_true_with(
+    raw1: &Coordinate,
+    bearing1: TrueBearing,
+    raw2: &Coordinate,
+    bearing2: TrueBearing,
+    ellipsoid: &Ellipsoid,
+) -> Option<Coordinate> {
+    let geodesic = Geodesic::new(ellipsoid);
+    let (coord, s1, s2) = intersect(
+        &geodesic,
+        raw1,
+        bearing1.degrees(),
+        raw2,
+        bearing2.degrees(),
+    )?;
+    (s1 >= -ENDPOINT_TOLERANCE && s2 >= -ENDPOINT_TOLERANCE).then(|| normalized(coord))
+}
+
+/// 获取WGS-84模型下两条航段（测地线段）的交点
+///
+/// # 参数
+///
+/// - `start1` - 第一条航段的起点
+/// - `end1` - 第一条航段的终点
+/// - `start2` - 第二条航段的起点
+/// - `end2` - 第二条航段的终点
+///
+/// # 返回值
+///
+/// 位于两条航段上的交点，经度在[-180, 180)范围内，不相交时返回`None`
+pub fn get_segment_intersection(
+    start1: &Coordinate,
+    end1: &Coordinate,
+    start2: &Coordinate,
+    end2: &Coordinate,
+) -> Option<Coordinate> {
+    get_segment_intersection_with(start1, end1, start2, end2, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下两条航段（测地线段）的交点
+///
+/// # 参数
+///
+/// - `start1` - 第一条航段的起点
+/// - `end1` - 第一条航段的终点
+/// - `start2` - 第二条航段的起点
+/// - `end2` - 第二条航段的终点
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 位于两条航段上的交点，经度在[-180, 180)范围内，不相交时返回`None`
+pub fn get_segment_intersection_with(
+    start1: &Coordinate,
+    end1: &Coordinate,
+    start2: &Coordinate,
+    end2: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> Option<Coordinate> {
+    let geodesic = Geodesic::new(ellipsoid);
+    let leg1 = geodesic.inverse(start1, end1);
+    let leg2 = geodesic.inverse(start2, end2);
+    let (coord, s1, s2) = intersect(
+        &geodesic,
+        start1,
+        leg1.initial_azimuth,
+        start2,
+        leg2.initial_azimuth,
+    )?;
+    let on_segment =
+        |s: f64, length: f64| s >= -ENDPOINT_TOLERANCE && s <= length + ENDPOINT_TOLERANCE;
+    (on_segment(s1, leg1.distance) && on_segment(s2, leg2.distance)).then(|| normalized(coord))
+}
+
+/// 沿射线的点到圆心的距离等于半径时，沿射线的距离
+///
+/// 以球面上的切点距离为初始值，用牛顿法修正。距离对沿射线距离的导数为射线方向与圆心方向夹角的余弦
+fn solve_on_line(
+    geodesic: &Geodesic,
+    raw: &Coordinate,
+    azimuth: f64,
+    center: &Coordinate,
+    radius: f64,
+    start: f64,
+) -> Option<f64> {
+    let mut s = start;
+    for _ in 0..MAX_ITERATIONS {
+        let point = geodesic.direct(raw, azimuth, s);
+        let to_point = geodesic.inverse(center, &point.coord);
+        let slope = (point.final_azimuth - to_point.final_azimuth)
+            .to_radians()
+            .cos();
+        let residual = to_point.distance - radius;
+        if residual.abs() < TOLERANCE {
+            return Some(s);
+        }
+        if slope.abs() < 1e-12 {
+            return None;
+        }
+        s -= residual / slope;
+    }
+    None
+}
+
+/// 按高斯-牛顿法求到各圆心距离最接近给定半径的点
+///
+/// 每次迭代在当前点的切平面内线性化，距离对位置的梯度为圆心指向该点的方向。
+/// 返回该点和法方程矩阵，法方程矩阵奇异（几何构型退化）或不收敛时返回`None`
+fn solve_ranges(
+    geodesic: &Geodesic,
+    ranges: &[(Coordinate, f64)],
+    start: Coordinate,
+) -> Option<(Coordinate, [[f64; 3]; 2])> {
+    let mut point = start;
+    for _ in 0..MAX_ITERATIONS {
+        // 法方程 [JᵀJ | -Jᵀf]，J的行为(东, 北)方向的梯度
+        let mut normal = [[0.0; 3]; 2];
+        for (center, radius) in ranges {
+            let to_point = geodesic.inverse(center, &point);
+            let (east, north) = to_point.final_azimuth.to_radians().sin_cos();
+            let residual = to_point.distance - radius;
+            normal[0][0] += east * east;
+            normal[0][1] += east * north;
+            normal[1][1] += north * north;
+            normal[0][2] -= east * residual;
+            normal[1][2] -= north * residual;
+        }
+        normal[1][0] = normal[0][1];
+        let det = normal[0][0] * normal[1][1] - normal[0][1] * normal[1][0];
+        if det.abs() < 1e-12 {
+            return None;
+        }
+        let east = (normal[1][1] * normal[0][2] - normal[0][1] * normal[1][2]) / det;
+        let north = (normal[0][0] * normal[1][2] - normal[1][0] * normal[0][2]) / det;
+        let step = east.hypot(north);
+        point = geodesic
+            .direct(&point, east.atan2(north).to_degrees(), step)
+            .coord;
+        if step < TOLERANCE {
+            return Some((point, normal));
+        }
+    }
+    None
+}
+
+/// 去掉与前一个候选点重合的点
+fn dedup(mut coords: Vec<Coordinate>, geodesic: &Geodesic) -> Vec<Coordinate> {
+    coords.dedup_by(|b, a| geodesic.inverse(a, b).distance < DUPLICATE_TOLERANCE);
+    coords
+}
+
+/// 获取WGS-84模型下射线（例如VOR径向线）与距离圆（例如另一台DME的距离弧）的交点
+///
+/// # 参数
+///
+/// - `raw` - 射线起点
+/// - `bearing` - 射线的磁方向
+/// - `mag_var` - 射线起点处的磁偏角（东正西负）
+/// - `center` - 圆心
+/// - `radius` - 半径，即地面距离，单位为米
+///
+/// # 返回值
+///
+/// 射线上的交点，按到射线起点的距离由近到远排列，最多两个，相切时为一个，不相交时为空。
+/// 经度在[-180, 180)范围内
+pub fn get_radial_dme_intersection(
+    raw: &Coordinate,
+    bearing: MagneticBearing,
+    mag_var: f64,
+    center: &Coordinate,
+    radius: f64,
+) -> Vec<Coordinate> {
+    get_radial_dme_intersection_with(raw, bearing, mag_var, center, radius, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下射线与距离圆的交点
+///
+/// # 参数
+///
+/// - `raw` - 射线起点
+/// - `bearing` - 射线的磁方向
+/// - `mag_var` - 射线起点处的磁偏角（东正西负）
+/// - `center` - 圆心
+/// - `radius` - 半径，即地面距离，单位为米
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 射线上的交点，按到射线起点的距离由近到远排列，最多两个，相切时为一个，不相交时为空。
+/// 经度在[-180, 180)范围内
+pub fn get_radial_dme_intersection_with(
+    raw: &Coordinate,
+    bearing: MagneticBearing,
+    mag_var: f64,
+    center: &Coordinate,
+    radius: f64,
+    ellipsoid: &Ellipsoid,
+) -> Vec<Coordinate> {
+    get_radial_dme_intersection_true_with(raw, bearing.to_true(mag_var), center, radius, ellipsoid)
+}
+
+/// 获取WGS-84模型下沿真方向的射线与距离圆的交点
+///
+/// # 参数
+///
+/// - `raw` - 射线起点
+/// - `bearing` - 射线的真方向
+/// - `center` - 圆心
+/// - `radius` - 半径，即地面距离，单位为米
+///
+/// # 返回值
+///
+/// 射线上的交点，按到射线起点的距离由近到远排列，最多两个，相切时为一个，不相交时为空。
+/// 经度在[-180, 180)范围内
+pub fn get_radial_dme_intersection_true(
+    raw: &Coordinate,
+    bearing: TrueBearing,
+    center: &Coordinate,
+    radius: f64,
+) -> Vec<Coordinate> {
+    get_radial_dme_intersection_true_with(raw, bearing, center, radius, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下沿真方向的射线与距离圆的交点
+///
+/// # 参数
+///
+/// - `raw` - 射线起点
+/// - `bearing` - 射线的真方向
+/// - `center` - 圆心
+/// - `radius` - 半径，即地面距离，单位为米
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 射线上的交点，按到射线起点的距离由近到远排列，最多两个，相切时为一个，不相交时为空。
+/// 经度在[-180, 180)范围内
+pub fn get_radial_dme_intersection_true_with(
+    raw: &Coordinate,
+    bearing: TrueBearing,
+    center: &Coordinate,
+    radius: f64,
+    ellipsoid: &Ellipsoid,
+) -> Vec<Coordinate> {
+    let geodesic = Geodesic::new(ellipsoid);
+    let azimuth = bearing.degrees();
+
+    // 球面上圆心到射线所在大圆的垂足和半弦长
+    let r = ellipsoid.a;
+    let to_center = geodesic.inverse(raw, center);
+    let sigma = to_center.distance / r;
+    let delta = (to_center.initial_azimuth - azimuth).to_radians();
+    let along = r * (sigma.sin() * delta.cos()).atan2(sigma.cos());
+    let cross = (sigma.sin() * delta.sin()).asin();
+    let cos_half = (radius / r).cos() / cross.cos();
+    if cos_half > 1.0 {
+        return Vec::new();
+    }
+    let half = r * cos_half.acos();
+
+    let coords = [along - half, along + half]
+        .into_iter()
+        .filter_map(|start| solve_on_line(&geodesic, raw, azimuth, center, radius, start))
+        .filter(|s| *s >= -ENDPOINT_TOLERANCE)
+        .map(|s| normalized(geodesic.direct(raw, azimuth, s.max(0.0)).coord))
+        .collect();
+    dedup(coords, &geodesic)
+}
+
+/// 获取WGS-84模型下两个距离圆的交点，即DME/DME定位的两个候选位置
+///
+/// # 参数
+///
+/// - `center1` - 第一个圆心
+/// - `radius1` - 第一个半径，即地面距离，单位为米
+/// - `center2` - 第二个圆心
+/// - `radius2` - 第二个半径，即地面距离，单位为米
+///
+/// # 返回值
+///
+/// 交点，最多两个，先为从第一个圆心看向第二个圆心时右侧的点，后为左侧的点。
+/// 相切时为一个，不相交或圆心重合时为空。经度在[-180, 180)范围内
+pub fn get_dme_dme_intersection(
+    center1: &Coordinate,
+    radius1: f64,
+    center2: &Coordinate,
+    radius2: f64,
+) -> Vec<Coordinate> {
+    get_dme_dme_intersection_with(center1, radius1, center2, radius2, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下两个距离圆的交点
+///
+/// # 参数
+///
+/// - `center1` - 第一个圆心
+/// - `radius1` - 第一个半径，即地面距离，单位为米
+/// - `center2` - 第二个圆心
+/// - `radius2` - 第二个半径，即地面距离，单位为米
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 交点，最多两个，先为从第一个圆心看向第二个圆心时右侧的点，后为左侧的点。
+/// 相切时为一个，不相交或圆心重合时为空。经度在[-180, 180)范围内
+pub fn get_dme_dme_intersection_with(
+    center1: &Coordinate,
+    radius1: f64,
+    center2: &Coordinate,
+    radius2: f64,
+    ellipsoid: &Ellipsoid,
+) -> Vec<Coordinate> {
+    let geodesic = Geodesic::new(ellipsoid);
+    let baseline = geodesic.inverse(center1, center2);
+    if baseline.distance < TOLERANCE {
+        return Vec::new();
+    }
+
+    // 球面余弦定理求第一个圆心处基线与交点方向的夹角
+    let r = ellipsoid.a;
+    let (d, r1, r2) = (baseline.distance / r, radius1 / r, radius2 / r);
+    let cos_angle = (r2.cos() - r1.cos() * d.cos()) / (r1.sin() * d.sin());
+    if cos_angle.abs() > 1.0 {
+        return Vec::new();
+    }
+    let angle = cos_angle.acos().to_degrees();
+
+    let ranges = [(*center1, radius1), (*center2, radius2)];
+    let coords = [angle, -angle]
+        .into_iter()
+        .filter_map(|offset| {
+            let start = geodesic
+                .direct(center1, baseline.initial_azimuth + offset, radius1)
+                .coord;
+            solve_ranges(&geodesic, &ranges, start)
+        })
+        .map(|(coord, _)| normalized(coord))
+        .collect();
+    dedup(coords, &geodesic)
+}
+
+/// 多台DME的最小二乘定位结果
+#[derive(Debug, PartialEq, Clone)]
+pub struct DmeFix {
+    /// 位置，经度在[-180, 180)范围内
+    pub coord: Coordinate,
+    /// 各台DME的残差（到该位置的距离减去测距），单位为米，顺序与输入相同
+    pub residuals: Vec<f64>,
+    /// 残差的均方根，单位为米
+    pub rms_residual: f64,
+    /// 水平精度因子，测距误差为1米时位置误差的均方根，几何构型越差越大
+    pub dilution: f64,
+}
+
+/// 获取WGS-84模型下由三台或更多DME测距按最小二乘得到的位置
+///
+/// # 参数
+///
+/// - `ranges` - DME位置和地面距离（单位为米）
+///
+/// # 返回值
+///
+/// 位置和残差、精度因子。少于三台、前两台的距离都为0、几何构型退化（例如所有DME在同一条测地线上）或不收敛时返回`None`
+pub fn get_dme_position(ranges: &[(Coordinate, f64)]) -> Option<DmeFix> {
+    get_dme_position_with(ranges, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下由三台或更多DME测距按最小二乘得到的位置
+///
+/// # 参数
+///
+/// - `ranges` - DME位置和地面距离（单位为米）
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 位置和残差、精度因子。少于三台、前两台的距离都为0、几何构型退化（例如所有DME在同一条测地线上）或不收敛时返回`None`
+pub fn get_dme_position_with(
+    ranges: &[(Coordinate, f64)],
+    ellipsoid: &Ellipsoid,
+) -> Option<DmeFix> {
+    if ranges.len() < 3 {
+        return None;
+    }
+    let geodesic = Geodesic::new(ellipsoid);
+    let residuals = |point: &Coordinate| -> Vec<f64> {
+        ranges
+            .iter()
+            .map(|(center, radius)| geodesic.inverse(center, point).distance - radius)
+            .collect()
+    };
+    let sum_squares = |point: &Coordinate| residuals(point).iter().map(|v| v * v).sum::<f64>();
+
+    // 以前两台DME的交点中残差较小的一个为初始值，不相交时取两圆心之间的点
+    let (center1, radius1) = ranges[0];
+    let (center2, radius2) = ranges[1];
+    let start = get_dme_dme_intersection_with(&center1, radius1, &center2, radius2, ellipsoid)
+        .into_iter()
+        .min_by(|a, b| sum_squares(a).total_cmp(&sum_squares(b)))
+        .or_else(|| {
+            // 两台的距离都为0时无法按比例取点
+            let sum = radius1 + radius2;
+            (sum > 0.0).then(|| {
+                let baseline = geodesic.inverse(&center1, &center2);
+                let distance = baseline.distance * radius1 / sum;
+                geodesic
+                    .direct(&center1, baseline.initial_azimuth, distance)
+                    .coord
+            })
+        })?;
+
+    let (coord, normal) = solve_ranges(&geodesic, ranges, start)?;
+    let coord = normalized(coord);
+    let residuals = residuals(&coord);
+    let rms_residual =
+        (residuals.iter().map(|v| v * v).sum::<f64>() / residuals.len() as f64).sqrt();
+    let det = normal[0][0] * normal[1][1] - normal[0][1] * normal[1][0];
+    let dilution = ((normal[0][0] + normal[1][1]) / det).sqrt();
+    Some(DmeFix {
+        coord,
+        residuals,
+        rms_residual,
+        dilution,
+    })
+}
@@ -1,6 +1,30 @@
-use crate::coordinate::Coordinate;
+use crate::algorithm::bearing::{MagneticBearing, TrueBearing};
+use crate::algorithm::geodesic::Geodesic;
+use crate::algorithm::rhumb::Rhumb;
+use crate::coordinate::{normalize_lon, Coordinate};
+use crate::magnetic::get_mag_var;
+use crate::Ellipsoid;
 
-/// 获取两点之间的方位角
+/// 将方位角规范化到[0, 360)范围内
+///
+/// # 参数
+///
+/// - `bearing` - 方位角（度数）
+///
+/// # 返回值
+///
+/// [0, 360)范围内的方位角
+pub fn normalize_bearing(bearing: f64) -> f64 {
+    let bearing = bearing.rem_euclid(360.0);
+    // 很小的负数取余后会舍入为360
+    if bearing >= 360.0 {
+        0.0
+    } else {
+        bearing
+    }
+}
+
+/// 获取WGS-84模型下两点之间的磁方位角，与`get_magnetic_bearing`相同
 ///
 /// # 参数
 ///
@@ -10,31 +34,210 @@ use crate::coordinate::Coordinate;
 ///
 /// # 返回值
 ///
-/// 方位角（度数）
-pub fn get_angle(raw: &Coordinate, target: &Coordinate, mag_var: f64) -> f64 {
-    const RJ: f64 = 6356752.3142;
-    const RC: f64 = 6378137.0000;
-    let ec = RJ + (RC - RJ) * (90.0 - raw.lat) / 90.0;
-    let ed = ec * raw.lat.to_radians().cos();
-    let dx = (raw.lon.to_radians() - target.lon.to_radians()) * ed;
-    let dy = (target.lat.to_radians() - raw.lat.to_radians()) * ec;
-    let mut angle = (dx / dy).abs().atan().to_degrees();
+/// 磁方位角，磁偏角为0时数值与真方位角相同
+pub fn get_angle(raw: &Coordinate, target: &Coordinate, mag_var: f64) -> MagneticBearing {
+    get_magnetic_bearing(raw, target, mag_var)
+}
 
-    let d_lo = target.lon - raw.lon;
-    let d_la = target.lat - raw.lat;
+/// 获取指定椭球下两点之间的磁方位角，与`get_magnetic_bearing_with`相同
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+/// - `MagVar` - 磁偏角（东正西负）
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 磁方位角，磁偏角为0时数值与真方位角相同
+pub fn get_angle_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    mag_var: f64,
+    ellipsoid: &Ellipsoid,
+) -> MagneticBearing {
+    get_magnetic_bearing_with(raw, target, mag_var, ellipsoid)
+}
 
-    if d_lo > 0.0 && d_la <= 0.0 {
-        angle = 90.0 - angle + 90.0;
-    } else if d_lo <= 0.0 && d_la < 0.0 {
-        angle += 180.0;
-    } else if d_lo < 0.0 && d_la >= 0.0 {
-        angle = 90.0 - angle + 270.0;
-    }
+/// 获取WGS-84模型下两点之间的磁方位角，磁偏角由内置WMM模型计算，参见`get_magnetic_bearing_wmm`
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+/// - `year` - 小数表示的年份，参见`magnetic::decimal_year`
+///
+/// # 返回值
+///
+/// 磁方位角
+pub fn get_angle_wmm(raw: &Coordinate, target: &Coordinate, year: f64) -> MagneticBearing {
+    get_magnetic_bearing_wmm(raw, target, year)
+}
+
+/// 获取WGS-84模型下从源坐标出发沿测地线前往目标坐标的起始真方位角
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+///
+/// # 返回值
+///
+/// 真方位角
+pub fn get_initial_bearing(raw: &Coordinate, target: &Coordinate) -> TrueBearing {
+    get_initial_bearing_with(raw, target, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下从源坐标出发沿测地线前往目标坐标的起始真方位角
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 真方位角
+pub fn get_initial_bearing_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> TrueBearing {
+    TrueBearing::new(
+        Geodesic::new(ellipsoid)
+            .inverse(raw, target)
+            .initial_azimuth,
+    )
+}
+
+/// 获取WGS-84模型下沿测地线到达目标坐标时的最终真方位角
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+///
+/// # 返回值
+///
+/// 真方位角
+pub fn get_final_bearing(raw: &Coordinate, target: &Coordinate) -> TrueBearing {
+    get_final_bearing_with(raw, target, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下沿测地线到达目标坐标时的最终真方位角
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 真方位角
+pub fn get_final_bearing_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> TrueBearing {
+    TrueBearing::new(Geodesic::new(ellipsoid).inverse(raw, target).final_azimuth)
+}
 
-    angle - mag_var
+/// 获取WGS-84模型下从目标坐标返回源坐标的起始真方位角，即最终方位角的反方向
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+///
+/// # 返回值
+///
+/// 真方位角
+pub fn get_reverse_bearing(raw: &Coordinate, target: &Coordinate) -> TrueBearing {
+    get_reverse_bearing_with(raw, target, &Ellipsoid::WGS84)
 }
 
-/// 获取球模型下两点之间的距离
+/// 获取指定椭球下从目标坐标返回源坐标的起始真方位角，即最终方位角的反方向
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 真方位角
+pub fn get_reverse_bearing_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> TrueBearing {
+    get_final_bearing_with(raw, target, ellipsoid).reciprocal()
+}
+
+/// 获取WGS-84模型下两点之间的起始磁方位角
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+/// - `MagVar` - 源坐标处的磁偏角（东正西负）
+///
+/// # 返回值
+///
+/// 磁方位角，即真方位角减去磁偏角
+pub fn get_magnetic_bearing(
+    raw: &Coordinate,
+    target: &Coordinate,
+    mag_var: f64,
+) -> MagneticBearing {
+    get_magnetic_bearing_with(raw, target, mag_var, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下两点之间的起始磁方位角
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+/// - `MagVar` - 源坐标处的磁偏角（东正西负）
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 磁方位角，即真方位角减去磁偏角
+pub fn get_magnetic_bearing_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    mag_var: f64,
+    ellipsoid: &Ellipsoid,
+) -> MagneticBearing {
+    get_initial_bearing_with(raw, target, ellipsoid).to_magnetic(mag_var)
+}
+
+/// 获取WGS-84模型下两点之间的起始磁方位角，使用内置WMM模型计算源坐标处海平面的磁偏角
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+/// - `year` - 小数表示的年份，参见`magnetic::decimal_year`
+///
+/// # 返回值
+///
+/// 磁方位角
+pub fn get_magnetic_bearing_wmm(
+    raw: &Coordinate,
+    target: &Coordinate,
+    year: f64,
+) -> MagneticBearing {
+    get_magnetic_bearing(raw, target, get_mag_var(raw, year))
+}
+
+/// 获取球模型下两点之间的距离，球的半径为WGS-84椭球的长半轴
 ///
 /// # 参数
 ///
@@ -45,7 +248,27 @@ pub fn get_angle(raw: &Coordinate, target: &Coordinate, mag_var: f64) -> f64 {
 ///
 /// 距离，单位为米
 pub fn get_circle_distance(raw: &Coordinate, target: &Coordinate) -> f64 {
-    const EARTH_RADIUS: f64 = 6378137.0;
+    get_circle_distance_with(raw, target, &Ellipsoid::WGS84)
+}
+
+/// 获取球模型下两点之间的距离，球的半径为指定椭球的长半轴
+///
+/// # 参数
+///
+/// - `raw` - 源坐标
+/// - `target` - 目标坐标
+/// - `ellipsoid` - 椭球，通常使用`Ellipsoid::SPHERE`或`Ellipsoid::sphere`
+///
+/// # 返回值
+///
+/// 距离，单位为米
+#[allow(clippy::let_and_return)]
+pub fn get_circle_distance_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> f64 {
+    let earth_radius = ellipsoid.a;
     let rad_lat1 = raw.lat.to_radians();
     let rad_lng1 = raw.lon.to_radians();
     let rad_lat2 = target.lat.to_radians();
@@ -56,11 +279,11 @@ pub fn get_circle_distance(raw: &Coordinate, target: &Coordinate) -> f64 {
         * ((a / 2.0).sin().powi(2) + rad_lat1.cos() * rad_lat2.cos() * (b / 2.0).sin().powi(2))
             .sqrt()
             .asin()
-        * EARTH_RADIUS;
+        * earth_radius;
     result
 }
 
-/// 获取WGS-84模型下两点之间的距离（Vincenty公式）
+/// 获取WGS-84模型下两点之间的测地线距离
 ///
 /// # 参数
 ///
@@ -71,81 +294,298 @@ pub fn get_circle_distance(raw: &Coordinate, target: &Coordinate) -> f64 {
 ///
 /// 距离，单位为米
 pub fn get_distance(raw: &Coordinate, target: &Coordinate) -> f64 {
-    const A: f64 = 6378137.0000;
-    const B: f64 = 6356752.3142;
-    const F: f64 = 1.0 / 298.2572236;
-
-    let l = (raw.lon - target.lon).to_radians();
-    let u1 = ((1.0 - F) * raw.lat.to_radians().tan()).atan();
-    let u2 = ((1.0 - F) * target.lat.to_radians().tan()).atan();
-
-    let sin_u1 = u1.sin();
-    let cos_u1 = u1.cos();
-    let sin_u2 = u2.sin();
-    let cos_u2 = u2.cos();
-
-    let mut lambda = l;
-    let mut lambda_p = std::f64::consts::PI;
-    let mut cos_sq_alpha = 0.0;
-    let mut sin_sigma = 0.0;
-    let mut cos_2_sigma_m = 0.0;
-    let mut cos_sigma = 0.0;
-    let mut sigma = 0.0;
-
-    let mut iter_count = 40;
-
-    while (lambda - lambda_p).abs() > 1e-12 && iter_count > 0 {
-        let sin_lambda = lambda.sin();
-        let cos_lambda = lambda.cos();
-
-        sin_sigma = ((cos_u2 * sin_lambda).powi(2)
-            + ((cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2)))
-        .sqrt();
-
-        if sin_sigma == 0.0 {
-            return 0.0; // coincident points
-        }
+    get_distance_with(raw, target, &Ellipsoid::WGS84)
+}
 
-        cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
-        sigma = sin_sigma.atan2(cos_sigma);
+/// 获取指定椭球下两点之间的测地线距离，参见`Geodesic::inverse`
+///
+/// # 参数
+///
+/// - `raw` - 原坐标
+/// - `target` - 目标坐标
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 距离，单位为米，对跖点附近也能得到结果
+pub fn get_distance_with(raw: &Coordinate, target: &Coordinate, ellipsoid: &Ellipsoid) -> f64 {
+    Geodesic::new(ellipsoid).inverse(raw, target).distance
+}
 
-        let alpha = (cos_u1 * cos_u2 * sin_lambda / sin_sigma).asin();
-        cos_sq_alpha = alpha.cos().powi(2);
+/// 获取WGS-84模型下两点之间沿恒向线的距离，经度差取较短的方向
+///
+/// # 参数
+///
+/// - `raw` - 原坐标
+/// - `target` - 目标坐标
+///
+/// # 返回值
+///
+/// 距离，单位为米
+pub fn get_rhumb_distance(raw: &Coordinate, target: &Coordinate) -> f64 {
+    get_rhumb_distance_with(raw, target, &Ellipsoid::WGS84)
+}
 
-        cos_2_sigma_m = cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha;
+/// 获取指定椭球下两点之间沿恒向线的距离，参见`Rhumb::inverse`
+///
+/// # 参数
+///
+/// - `raw` - 原坐标
+/// - `target` - 目标坐标
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 距离，单位为米
+pub fn get_rhumb_distance_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> f64 {
+    Rhumb::new(ellipsoid).inverse(raw, target).distance
+}
 
-        let c = F / 16.0 * cos_sq_alpha * (4.0 + F * (4.0 - 3.0 * cos_sq_alpha));
+/// 获取WGS-84模型下两点之间恒向线的航向，经度差取较短的方向
+///
+/// # 参数
+///
+/// - `raw` - 原坐标
+/// - `target` - 目标坐标
+/// - `MagVar` - 磁偏角（东正西负），使用真方向时为0
+///
+/// # 返回值
+///
+/// 磁航向，即真航向减去磁偏角，磁偏角为0时数值与真航向相同
+pub fn get_rhumb_bearing(raw: &Coordinate, target: &Coordinate, mag_var: f64) -> MagneticBearing {
+    get_rhumb_bearing_with(raw, target, mag_var, &Ellipsoid::WGS84)
+}
 
-        lambda_p = lambda;
+/// 获取指定椭球下两点之间恒向线的航向，参见`Rhumb::inverse`
+///
+/// # 参数
+///
+/// - `raw` - 原坐标
+/// - `target` - 目标坐标
+/// - `MagVar` - 磁偏角（东正西负），使用真方向时为0
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 磁航向，即真航向减去磁偏角，磁偏角为0时数值与真航向相同
+pub fn get_rhumb_bearing_with(
+    raw: &Coordinate,
+    target: &Coordinate,
+    mag_var: f64,
+    ellipsoid: &Ellipsoid,
+) -> MagneticBearing {
+    TrueBearing::new(Rhumb::new(ellipsoid).inverse(raw, target).bearing).to_magnetic(mag_var)
+}
 
-        lambda = l
-            + ((1.0 - c)
-                * F
-                * alpha.sin()
-                * (sigma
-                    + (c * sin_sigma
-                        * (cos_2_sigma_m
-                            + (c * cos_sigma * (-1.0 + 2.0 * cos_2_sigma_m * cos_2_sigma_m))))));
+/// 获取WGS-84模型下两点之间恒向线的磁航向，使用内置WMM模型计算原坐标处海平面的磁偏角
+///
+/// # 参数
+///
+/// - `raw` - 原坐标
+/// - `target` - 目标坐标
+/// - `year` - 小数表示的年份，参见`magnetic::decimal_year`
+///
+/// # 返回值
+///
+/// 磁航向
+pub fn get_rhumb_bearing_wmm(raw: &Coordinate, target: &Coordinate, year: f64) -> MagneticBearing {
+    get_rhumb_bearing(raw, target, get_mag_var(raw, year))
+}
 
-        iter_count -= 1;
+/// 求测地线垂足时迭代的最大次数
+const TRACK_MAX_ITERATIONS: usize = 50;
+/// 求测地线垂足时的收敛条件，单位为米
+const TRACK_TOLERANCE: f64 = 1e-9;
+
+/// 点相对航段所在测地线的位置
+struct TrackPosition {
+    /// 从航段起点到垂足的距离，垂足在起点之前时为负
+    along: f64,
+    /// 从垂足到点的距离，点在航向右侧时为正
+    cross: f64,
+    /// 垂足
+    foot: Coordinate,
+    /// 航段长度
+    length: f64,
+}
+
+/// 求点到航段所在测地线的垂足
+///
+/// 沿测地线移动试探点，每次按球面三角关系修正沿航迹距离，直到试探点到该点的测地线与航段垂直
+fn get_track_position(
+    point: &Coordinate,
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> TrackPosition {
+    let geodesic = Geodesic::new(ellipsoid);
+    let leg = geodesic.inverse(raw, target);
+    if leg.distance == 0.0 {
+        // 航段退化为一点时方向不确定
+        return TrackPosition {
+            along: 0.0,
+            cross: geodesic.inverse(raw, point).distance,
+            foot: *raw,
+            length: 0.0,
+        };
     }
 
-    if iter_count == 0 {
-        return f64::NAN; // formula failed to converge
+    let radius = ellipsoid.a;
+    let mut along = 0.0;
+    let mut foot = *raw;
+    let mut cross = 0.0;
+    for _ in 0..TRACK_MAX_ITERATIONS {
+        let direct = geodesic.direct(raw, leg.initial_azimuth, along);
+        foot = direct.coord;
+        let to_point = geodesic.inverse(&foot, point);
+        let delta = (to_point.initial_azimuth - direct.final_azimuth).to_radians();
+        let sigma = to_point.distance / radius;
+        cross = to_point.distance.copysign(delta.sin());
+        let step = radius * (sigma.sin() * delta.cos()).atan2(sigma.cos());
+        along += step;
+        if step.abs() < TRACK_TOLERANCE {
+            break;
+        }
+    }
+
+    TrackPosition {
+        along,
+        cross,
+        foot: Coordinate {
+            lat: foot.lat,
+            lon: normalize_lon(foot.lon),
+        },
+        length: leg.distance,
     }
+}
+
+/// 获取WGS-84模型下点到航段所在测地线的偏航距离
+///
+/// # 参数
+///
+/// - `point` - 点，例如航空器位置
+/// - `raw` - 航段起点
+/// - `target` - 航段终点
+///
+/// # 返回值
+///
+/// 点到测地线的最短距离，单位为米，点在航向右侧时为正，左侧为负
+pub fn get_cross_track_distance(point: &Coordinate, raw: &Coordinate, target: &Coordinate) -> f64 {
+    get_cross_track_distance_with(point, raw, target, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下点到航段所在测地线的偏航距离
+///
+/// # 参数
+///
+/// - `point` - 点，例如航空器位置
+/// - `raw` - 航段起点
+/// - `target` - 航段终点
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 点到测地线的最短距离，单位为米，点在航向右侧时为正，左侧为负
+pub fn get_cross_track_distance_with(
+    point: &Coordinate,
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> f64 {
+    get_track_position(point, raw, target, ellipsoid).cross
+}
+
+/// 获取WGS-84模型下点在航段上的沿航迹距离
+///
+/// # 参数
+///
+/// - `point` - 点，例如航空器位置
+/// - `raw` - 航段起点
+/// - `target` - 航段终点
+///
+/// # 返回值
+///
+/// 从航段起点沿测地线到垂足的距离，单位为米，垂足在起点之前时为负，超过终点时大于航段长度
+pub fn get_along_track_distance(point: &Coordinate, raw: &Coordinate, target: &Coordinate) -> f64 {
+    get_along_track_distance_with(point, raw, target, &Ellipsoid::WGS84)
+}
+
+/// 获取指定椭球下点在航段上的沿航迹距离
+///
+/// # 参数
+///
+/// - `point` - 点，例如航空器位置
+/// - `raw` - 航段起点
+/// - `target` - 航段终点
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 从航段起点沿测地线到垂足的距离，单位为米，垂足在起点之前时为负，超过终点时大于航段长度
+pub fn get_along_track_distance_with(
+    point: &Coordinate,
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> f64 {
+    get_track_position(point, raw, target, ellipsoid).along
+}
+
+/// 获取WGS-84模型下航段上距离点最近的位置
+///
+/// # 参数
+///
+/// - `point` - 点，例如航空器位置
+/// - `raw` - 航段起点
+/// - `target` - 航段终点
+///
+/// # 返回值
+///
+/// 垂足在航段上时为垂足，否则为较近的端点，经度在[-180, 180)范围内
+pub fn get_closest_coord_on_segment(
+    point: &Coordinate,
+    raw: &Coordinate,
+    target: &Coordinate,
+) -> Coordinate {
+    get_closest_coord_on_segment_with(point, raw, target, &Ellipsoid::WGS84)
+}
 
-    let u_sq = cos_sq_alpha * (A * A - B * B) / (B * B);
-    let a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
-    let b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
-    let delta_sigma = b
-        * sin_sigma
-        * (cos_2_sigma_m
-            + b / 4.0
-                * (cos_sigma * (-1.0 + 2.0 * cos_2_sigma_m.powi(2))
-                    - b / 6.0
-                        * cos_2_sigma_m
-                        * (-3.0 + 4.0 * sin_sigma.powi(2))
-                        * (-3.0 + 4.0 * cos_2_sigma_m.powi(2))));
-
-    B * a * (sigma - delta_sigma)
+/// 获取指定椭球下航段上距离点最近的位置
+///
+/// # 参数
+///
+/// - `point` - 点，例如航空器位置
+/// - `raw` - 航段起点
+/// - `target` - 航段终点
+/// - `ellipsoid` - 椭球
+///
+/// # 返回值
+///
+/// 垂足在航段上时为垂足，否则为较近的端点，经度在[-180, 180)范围内
+pub fn get_closest_coord_on_segment_with(
+    point: &Coordinate,
+    raw: &Coordinate,
+    target: &Coordinate,
+    ellipsoid: &Ellipsoid,
+) -> Coordinate {
+    let position = get_track_position(point, raw, target, ellipsoid);
+    if position.along > 0.0 && position.along < position.length {
+        return position.foot;
+    }
+    // 垂足在航段外时取较近的端点
+    let geodesic = Geodesic::new(ellipsoid);
+    let nearest =
+        if geodesic.inverse(point, raw).distance <= geodesic.inverse(point, target).distance {
+            raw
+        } else {
+            target
+        };
+    Coordinate {
+        lat: nearest.lat,
+        lon: normalize_lon(nearest.lon),
+    }
 }
@@ -10,6 +10,7 @@ use crate::Coordinate;
 /// # 返回值
 ///
 /// 目标点是否在指定区域内
+#[allow(clippy::collapsible_if)]
 pub fn is_point_in_area(point: &Coordinate, area: &[Coordinate]) -> bool {
     let a_lat = point.lat;
     let a_lon = point.lon;
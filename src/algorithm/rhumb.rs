@@ -0,0 +1,179 @@
+//! 椭球上的恒向线（等角航线）计算
+//!
+//! 恒向线与所有子午线的夹角相同，在墨卡托投影上为直线。
+//! 沿恒向线的子午线弧长与等量纬度的变化成正比，由此得到距离和航向
+
+use super::measurement::normalize_bearing;
+use crate::coordinate::normalize_lon;
+use crate::{Coordinate, Ellipsoid};
+
+/// 纬度差（弧度）小于该值时视为东西向航线，用中间纬度处的纬线圈半径计算
+const EAST_WEST_THRESHOLD: f64 = 1e-5;
+/// 由子午线弧长反算纬度时牛顿迭代的最大次数
+const MAX_ITERATIONS: usize = 10;
+
+/// 恒向线反解的结果
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct RhumbInverse {
+    /// 距离，单位为米
+    pub distance: f64,
+    /// 航向（真方位角），单位为度，范围[0, 360)
+    pub bearing: f64,
+}
+
+/// 指定椭球上的恒向线计算
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Rhumb {
+    a: f64,
+    n: f64,
+    e: f64,
+    e2: f64,
+    /// 子午线弧长级数的系数
+    arc: [f64; 5],
+}
+
+impl Default for Rhumb {
+    /// WGS-84椭球
+    fn default() -> Self {
+        Rhumb::new(&Ellipsoid::WGS84)
+    }
+}
+
+impl Rhumb {
+    /// 创建恒向线计算
+    ///
+    /// # 参数
+    ///
+    /// - `ellipsoid` - 椭球
+    pub fn new(ellipsoid: &Ellipsoid) -> Self {
+        let n = ellipsoid.f / (2.0 - ellipsoid.f);
+        let (n2, n3, n4) = (n * n, n * n * n, n * n * n * n);
+        let e2 = ellipsoid.e2();
+        Rhumb {
+            a: ellipsoid.a,
+            n,
+            e: e2.sqrt(),
+            e2,
+            // Helmert级数，截断误差在n^5量级
+            arc: [
+                1.0 + n2 / 4.0 + n4 / 64.0,
+                -1.5 * (n - n3 / 8.0),
+                15.0 / 16.0 * (n2 - n4 / 4.0),
+                -35.0 / 48.0 * n3,
+                315.0 / 512.0 * n4,
+            ],
+        }
+    }
+
+    /// 赤道到纬度`phi`（弧度）的子午线弧长
+    fn meridian_arc(&self, phi: f64) -> f64 {
+        let series = self.arc[0] * phi
+            + (1..5)
+                .map(|k| self.arc[k] * (2.0 * k as f64 * phi).sin())
+                .sum::<f64>();
+        self.a / (1.0 + self.n) * series
+    }
+
+    /// 子午线曲率半径
+    fn meridian_radius(&self, phi: f64) -> f64 {
+        let w2 = 1.0 - self.e2 * phi.sin().powi(2);
+        self.a * (1.0 - self.e2) / (w2 * w2.sqrt())
+    }
+
+    /// 纬线圈半径
+    fn parallel_radius(&self, phi: f64) -> f64 {
+        self.a * phi.cos() / (1.0 - self.e2 * phi.sin().powi(2)).sqrt()
+    }
+
+    /// 等量纬度
+    fn isometric_latitude(&self, phi: f64) -> f64 {
+        phi.tan().asinh() - self.e * (self.e * phi.sin()).atanh()
+    }
+
+    /// 由子午线弧长反算纬度（弧度）
+    fn latitude_of_arc(&self, arc: f64) -> f64 {
+        let quarter = self.meridian_arc(std::f64::consts::FRAC_PI_2);
+        let mut phi = arc / quarter * std::f64::consts::FRAC_PI_2;
+        for _ in 0..MAX_ITERATIONS {
+            let delta = (self.meridian_arc(phi) - arc) / self.meridian_radius(phi);
+            phi -= delta;
+            if delta.abs() < 1e-15 {
+                break;
+            }
+        }
+        phi
+    }
+
+    /// 单位等量纬度变化对应的子午线弧长，东西向航线取中间纬度处的极限值
+    fn arc_per_isometric(&self, phi1: f64, phi2: f64) -> f64 {
+        if (phi2 - phi1).abs() < EAST_WEST_THRESHOLD {
+            self.parallel_radius((phi1 + phi2) / 2.0)
+        } else {
+            (self.meridian_arc(phi2) - self.meridian_arc(phi1))
+                / (self.isometric_latitude(phi2) - self.isometric_latitude(phi1))
+        }
+    }
+
+    /// 恒向线反解，经度差取较短的方向
+    ///
+    /// # 参数
+    ///
+    /// - `raw` - 起点
+    /// - `target` - 终点
+    ///
+    /// # 返回值
+    ///
+    /// 距离和航向。任一点在极点时恒向线为子午线
+    pub fn inverse(&self, raw: &Coordinate, target: &Coordinate) -> RhumbInverse {
+        let (phi1, phi2) = (raw.lat.to_radians(), target.lat.to_radians());
+        if raw.lat.abs() >= 90.0 || target.lat.abs() >= 90.0 {
+            let distance = (self.meridian_arc(phi2) - self.meridian_arc(phi1)).abs();
+            let bearing = if target.lat < raw.lat { 180.0 } else { 0.0 };
+            return RhumbInverse { distance, bearing };
+        }
+
+        let dlambda = normalize_lon(target.lon - raw.lon).to_radians();
+        let dpsi = self.isometric_latitude(phi2) - self.isometric_latitude(phi1);
+        let distance = dlambda.hypot(dpsi) * self.arc_per_isometric(phi1, phi2);
+        RhumbInverse {
+            distance,
+            bearing: normalize_bearing(dlambda.atan2(dpsi).to_degrees()),
+        }
+    }
+
+    /// 恒向线正解
+    ///
+    /// # 参数
+    ///
+    /// - `raw` - 起点
+    /// - `bearing` - 航向（真方位角），单位为度
+    /// - `distance` - 距离，单位为米，可以为负数
+    ///
+    /// # 返回值
+    ///
+    /// 终点坐标，经度在[-180, 180)范围内。
+    /// 恒向线在有限距离内到达极点，超过时停在极点，经度保持起点经度
+    pub fn direct(&self, raw: &Coordinate, bearing: f64, distance: f64) -> Coordinate {
+        let phi1 = raw.lat.to_radians();
+        let (sin_alpha, cos_alpha) = bearing.to_radians().sin_cos();
+        let quarter = self.meridian_arc(std::f64::consts::FRAC_PI_2);
+        let arc = self.meridian_arc(phi1) + distance * cos_alpha;
+        if arc.abs() >= quarter {
+            return Coordinate {
+                lat: 90f64.copysign(arc),
+                lon: normalize_lon(raw.lon),
+            };
+        }
+
+        let phi2 = self.latitude_of_arc(arc);
+        let dlambda = if (phi2 - phi1).abs() < EAST_WEST_THRESHOLD {
+            distance * sin_alpha / self.parallel_radius((phi1 + phi2) / 2.0)
+        } else {
+            (self.isometric_latitude(phi2) - self.isometric_latitude(phi1)) * sin_alpha / cos_alpha
+        };
+        Coordinate {
+            lat: phi2.to_degrees(),
+            lon: normalize_lon(raw.lon + dlambda.to_degrees()),
+        }
+    }
+}
@@ -1,9 +1,28 @@
-use crate::coordinate::CoordParseError::NoMatchingFormat;
-use lazy_static::lazy_static;
-use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::fmt::{write, Display, Formatter};
-use std::{collections::HashMap, str::FromStr};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+
+mod dms;
+mod extract;
+mod format;
+mod geohash;
+mod mgrs;
+mod pbd;
+mod scanner;
+mod utm;
+
+pub use dms::{Dms, DmsError, Hemisphere};
+pub use extract::CoordMatches;
+pub use format::{CoordDisplay, CoordFormat};
+pub use geohash::{Direction, Geohash, GeohashBounds, GeohashError, GEOHASH_MAX_PRECISION};
+pub use pbd::{Pbd, PbdError};
+pub use utm::{GridError, Utm};
+
+pub(crate) use utm::TransverseMercator;
+
+#[cfg(feature = "regex")]
+pub mod regex_parser;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Copy, Clone)]
 pub struct Coordinate {
@@ -13,80 +32,123 @@ pub struct Coordinate {
     pub lon: f64,
 }
 
-lazy_static! {
-    ///  # 表示坐标格式的正则表达式（单个数值）
-    ///  - 第一组是正负
-    ///  - 第二组是度
-    ///  - 第三组是分
-    ///  - 第四组是秒
-    static ref RE_SINGLE: Vec<Regex> = vec![
-        Regex::new(r"^([NSEW])([01]?\d{2})(\d{2}\.\d*)$").unwrap(),
-        Regex::new(r"^([NSEW])([01]?\d{2})(\d{2})(\d{2}\.\d*)$").unwrap(),
-        Regex::new(r"^([NSEW])([01]?\d{2})(\d{2})(\d{2})(\d{2})$").unwrap(),
-        Regex::new(r"^([NSEW])([01]?\d{2})(\d{2})(\d{2})$").unwrap(),
-        Regex::new(r"^([NSEW])(\d{3})\.(\d{2})\.(\d{2}\.\d{3})$").unwrap(),
-        Regex::new(r"^([NSEW]) ([01]?\d{2}) (\d{2}\.\d*)$").unwrap(),
-        Regex::new(r"^([+-]?)(\d{2,3}\.\d*)$").unwrap(),
-    ];
-
-    /// 表示单位换算时的比例
-    static ref RATIO: HashMap<usize, f64> = vec![(2, 1.0), (3, 1.0 / 60.0), (4, 1.0 / 3600.0), (5, 1.0 / 360000.0)]
-        .into_iter()
-        .collect();
-
-    /// 表示坐标格式的正则表达式（经纬度都包含）
-    static ref RE_COMBINE: Vec<Regex> = vec![
-        Regex::new(r"^([NS].*?) ?([EW].*?)$").unwrap(),  // Nxxxx.xExxxxx.x、Nxxxxxx.xExxxxxxx.x、NxxxxxxExxxxxxx、Nxxx.xx.xx.xxx Exxx.xx.xx.xxx、N xx xx.xxxxxx E xxx xx.xxxxxx
-        Regex::new(r"^(.*?),(.*?)$").unwrap(),         // xx.xxxxxxxxxxxxxx,xxx.xxxxxxxxxxxxxxx
-        Regex::new(r"^([^ ]*?) ([^ ]*?)$").unwrap()    // +xx.xxxxxxxx +xxx.xxxxxx
-    ];
-}
-
-/// 处理坐标时可能出现的错误类型
-#[derive(Debug, PartialEq)]
-pub enum CoordParseError {
+/// 处理坐标时可能出现的错误类别
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CoordParseErrorKind {
     /// 纬度处理错误
     LatParseError,
     /// 经度处理错误
     LonParseError,
     /// 没有匹配的格式
     NoMatchingFormat,
+    /// 纬度超出[-90, 90]范围
+    LatOutOfRange,
+    /// 经度超出[-180, 180]范围
+    LonOutOfRange,
+    /// 无法确定经纬度的先后顺序
+    AmbiguousAxisOrder,
+}
+
+/// 处理坐标时可能出现的错误
+#[derive(Debug, PartialEq, Clone)]
+pub struct CoordParseError {
+    /// 错误类别
+    pub kind: CoordParseErrorKind,
+    /// 原始输入
+    pub input: String,
+    /// 出错部分在原始输入中的字节范围
+    pub span: Range<usize>,
+    /// 最接近的格式（能匹配的前缀最长的格式），没有任何格式能匹配开头时为`None`
+    pub closest: Option<CoordFormat>,
+    /// 可接受的格式
+    pub expected: Vec<CoordFormat>,
+}
+
+impl CoordParseError {
+    fn new(
+        kind: CoordParseErrorKind,
+        input: &str,
+        span: Range<usize>,
+        closest: Option<CoordFormat>,
+        format: Option<CoordFormat>,
+    ) -> Self {
+        CoordParseError {
+            kind,
+            input: input.to_string(),
+            span,
+            closest,
+            expected: match format {
+                Some(format) => vec![format],
+                None => CoordFormat::ALL.to_vec(),
+            },
+        }
+    }
+
+    /// 出错部分的文本
+    pub fn fragment(&self) -> &str {
+        &self.input[self.span.clone()]
+    }
 }
 
 impl Display for CoordParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        let (what, expected) = match self.kind {
+            CoordParseErrorKind::LatParseError => ("invalid latitude", None),
+            CoordParseErrorKind::LonParseError => ("invalid longitude", None),
+            CoordParseErrorKind::NoMatchingFormat => ("unrecognized coordinate", None),
+            CoordParseErrorKind::LatOutOfRange => ("latitude out of range", Some("[-90, 90]")),
+            CoordParseErrorKind::LonOutOfRange => ("longitude out of range", Some("[-180, 180]")),
+            CoordParseErrorKind::AmbiguousAxisOrder => (
+                "ambiguous axis order",
+                Some("hemisphere letters or a value beyond ±90°"),
+            ),
+        };
+        write!(
+            f,
+            "{} {:?} at {}..{} of {:?}; expected ",
+            what,
+            self.fragment(),
+            self.span.start,
+            self.span.end,
+            self.input,
+        )?;
+        match expected {
+            Some(range) => write!(f, "{}", range)?,
+            None => {
+                let expected: Vec<&str> = self.expected.iter().map(|e| e.description()).collect();
+                write!(f, "one of: {}", expected.join(", "))?
+            }
+        }
+        if let Some(closest) = self.closest {
+            write!(f, " (closest: {})", closest.description())?;
+        }
+        Ok(())
     }
 }
 
 impl std::error::Error for CoordParseError {}
 
-/// 将输入的字符串变成f64类型的数值
-fn parse(s: &str) -> Result<f64, CoordParseError> {
-    RE_SINGLE
-        .iter()
-        .find_map(|r| r.captures(s))
-        .map(|group| {
-            let sum = (2..group.len())
-                .map(|i| {
-                    group
-                        .get(i)
-                        .and_then(|m| m.as_str().parse::<f64>().ok())
-                        .map(|num| num * RATIO.get(&i).unwrap_or(&1.0))
-                        .unwrap_or(0.0)
-                })
-                .sum::<f64>();
-            let sign = group
-                .get(1)
-                .map(|m| match m.as_str() {
-                    "S" | "W" | "-" => -1.0,
-                    _ => 1.0,
-                })
-                .unwrap_or(1.0);
-
-            sum * sign
-        })
-        .ok_or(NoMatchingFormat)
+/// 输入中经纬度的先后顺序
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum AxisOrder {
+    /// 纬度在前，经度在后
+    #[default]
+    LatLon,
+    /// 经度在前，纬度在后，例如GeoJSON的`116.5,40.5`
+    LonLat,
+    /// 根据半球标识判断先后顺序；没有半球标识时，绝对值超过90的一方为经度
+    Auto,
+}
+
+/// 坐标解析选项
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub struct ParseOptions {
+    /// 检查经纬度范围，超出范围时返回错误而不是返回坐标
+    pub check_range: bool,
+    /// 限定经纬度的格式，为`None`时经纬度可以是任意格式
+    pub format: Option<CoordFormat>,
+    /// 经纬度的先后顺序。不是`LatLon`时总会检查纬度范围，以免顺序错误时得到无效的坐标
+    pub axis_order: AxisOrder,
 }
 
 impl FromStr for Coordinate {
@@ -94,29 +156,281 @@ impl FromStr for Coordinate {
 
     /// 通过字符串创建Coordinate
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        RE_COMBINE
-            .iter()
-            .find_map(|r| r.captures(s))
-            .map(|group| {
-                let lat = group
-                    .get(1)
-                    .ok_or(Self::Err::LatParseError)
-                    .and_then(|m| parse(m.as_str()).map_err(|_| Self::Err::LatParseError))?;
+        Coordinate::parse_with(s, &ParseOptions::default())
+    }
+}
 
-                let lon = group
-                    .get(2)
-                    .ok_or(Self::Err::LonParseError)
-                    .and_then(|m| parse(m.as_str()).map_err(|_| Self::Err::LonParseError))?;
+impl Eq for Coordinate {}
 
-                Ok(Coordinate { lat, lon })
-            })
-            .unwrap_or(Err(NoMatchingFormat))
+impl Display for Coordinate {
+    /// 以`[+-]DD(D).D`格式输出，例如`40.510000,116.510000`，可以用`{:.3}`指定小数位数
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.display(CoordFormat::Decimal), f)
     }
 }
 
-impl Eq for Coordinate {}
+/// 检测字符串符合的坐标格式
+///
+/// # 参数
+///
+/// - `s` - 坐标字符串
+///
+/// # 返回值
+///
+/// 经度和纬度都符合的格式，按`Coordinate::from_str`尝试的顺序排列。
+/// 经度和纬度格式不同或无法处理时为空
+pub fn detect_format(s: &str) -> Vec<CoordFormat> {
+    CoordFormat::ALL
+        .into_iter()
+        .filter(|&format| Coordinate::parse_as(s, format).is_ok())
+        .collect()
+}
+
+/// 坐标超出范围时的错误类型
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum CoordRangeError {
+    /// 纬度不在[-90, 90]范围内
+    LatOutOfRange(f64),
+    /// 经度不在[-180, 180]范围内
+    LonOutOfRange(f64),
+}
+
+impl Display for CoordRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoordRangeError::LatOutOfRange(lat) => {
+                write!(f, "latitude {} out of range [-90, 90]", lat)
+            }
+            CoordRangeError::LonOutOfRange(lon) => {
+                write!(f, "longitude {} out of range [-180, 180]", lon)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CoordRangeError {}
+
+/// 纬度是否在[-90, 90]范围内
+fn is_valid_lat(lat: f64) -> bool {
+    (-90.0..=90.0).contains(&lat)
+}
+
+/// 经度是否在[-180, 180]范围内
+fn is_valid_lon(lon: f64) -> bool {
+    (-180.0..=180.0).contains(&lon)
+}
+
+/// 将经度折算到[-180, 180)范围内，已在范围内的经度保持不变
+pub(crate) fn normalize_lon(lon: f64) -> f64 {
+    if (-180.0..180.0).contains(&lon) {
+        lon
+    } else {
+        let lon = (lon + 180.0).rem_euclid(360.0) - 180.0;
+        // rem_euclid对极小的负数可能得到360
+        if lon >= 180.0 {
+            -180.0
+        } else {
+            lon
+        }
+    }
+}
+
+/// 按网格坐标格式处理字符串
+fn parse_grid(s: &str, format: CoordFormat) -> Option<Coordinate> {
+    match format {
+        CoordFormat::Utm => s.parse::<Utm>().ok()?.to_coordinate().ok(),
+        CoordFormat::Mgrs => Coordinate::from_mgrs(s).ok(),
+        _ => None,
+    }
+}
+
+/// 自动判断纬度是否在前
+///
+/// 优先使用半球标识（`N`/`S`表示纬度，`E`/`W`表示经度），
+/// 没有半球标识或两部分的标识矛盾时，绝对值超过90的一方为经度
+///
+/// # 返回值
+///
+/// 纬度在前时为`true`，无法判断时为`None`
+fn lat_first(s: &str, scanned: &scanner::Scanned) -> Option<bool> {
+    let first = scanner::hemisphere_hint(&s[scanned.first_span.clone()]).map(|h| h.is_lat());
+    let second = scanner::hemisphere_hint(&s[scanned.second_span.clone()]).map(|h| h.is_lat());
+    match (first, second) {
+        (Some(true), None | Some(false)) | (None, Some(false)) => Some(true),
+        (Some(false), None | Some(true)) | (None, Some(true)) => Some(false),
+        _ => match (is_valid_lat(scanned.first), is_valid_lat(scanned.second)) {
+            (true, false) => Some(true),
+            (false, true) => Some(false),
+            _ => None,
+        },
+    }
+}
 
 impl Coordinate {
+    /// 创建经过范围检查的坐标
+    ///
+    /// # 参数
+    ///
+    /// - `lat` - 纬度，范围为[-90, 90]
+    /// - `lon` - 经度，范围为[-180, 180]
+    ///
+    /// # 返回值
+    ///
+    /// 坐标，超出范围时返回错误
+    pub fn new(lat: f64, lon: f64) -> Result<Self, CoordRangeError> {
+        if !is_valid_lat(lat) {
+            return Err(CoordRangeError::LatOutOfRange(lat));
+        }
+        if !is_valid_lon(lon) {
+            return Err(CoordRangeError::LonOutOfRange(lon));
+        }
+        Ok(Coordinate { lat, lon })
+    }
+
+    /// 经纬度是否都在有效范围内
+    pub fn is_valid(&self) -> bool {
+        is_valid_lat(self.lat) && is_valid_lon(self.lon)
+    }
+
+    /// 规范化坐标，经度折算到[-180, 180)范围内
+    ///
+    /// # 返回值
+    ///
+    /// 规范化后的坐标，纬度超出[-90, 90]或经度不是有限值时返回错误
+    pub fn normalize(&self) -> Result<Self, CoordRangeError> {
+        if !is_valid_lat(self.lat) {
+            return Err(CoordRangeError::LatOutOfRange(self.lat));
+        }
+        if !self.lon.is_finite() {
+            return Err(CoordRangeError::LonOutOfRange(self.lon));
+        }
+        Ok(Coordinate {
+            lat: self.lat,
+            lon: normalize_lon(self.lon),
+        })
+    }
+
+    /// 按指定的选项通过字符串创建Coordinate
+    ///
+    /// # 参数
+    ///
+    /// - `s` - 坐标字符串
+    /// - `options` - 解析选项
+    ///
+    /// # 返回值
+    ///
+    /// 坐标，无法处理或（检查范围时）超出范围时返回错误
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<Self, CoordParseError> {
+        Coordinate::try_parse(s, options).map_err(|(kind, span, closest)| {
+            CoordParseError::new(kind, s, span, closest, options.format)
+        })
+    }
+
+    /// `parse_with`的实现，错误只包含类别、字节范围和最接近的格式，不复制输入，
+    /// 供`find_iter`反复尝试时使用
+    pub(crate) fn try_parse(s: &str, options: &ParseOptions) -> Result<Self, scanner::ScanError> {
+        if let Some(format @ (CoordFormat::Utm | CoordFormat::Mgrs)) = options.format {
+            return parse_grid(s, format).ok_or((
+                CoordParseErrorKind::NoMatchingFormat,
+                0..s.len(),
+                None,
+            ));
+        }
+
+        let scanned = scanner::scan(s, options.format);
+        // 网格坐标不能拆分为经纬度两部分，只在其他格式都无法处理时尝试
+        if scanned.is_err() && options.format.is_none() {
+            let grid = parse_grid(s, CoordFormat::Utm).or_else(|| parse_grid(s, CoordFormat::Mgrs));
+            if let Some(coord) = grid {
+                return Ok(coord);
+            }
+        }
+
+        let scanned = scanned.map_err(|(kind, span, closest)| {
+            // 扫描器按出现顺序报告错误，需要按实际的轴顺序换算
+            let first_is_lat = match options.axis_order {
+                AxisOrder::LatLon => true,
+                AxisOrder::LonLat => false,
+                AxisOrder::Auto => {
+                    let is_first = kind == CoordParseErrorKind::LatParseError;
+                    match scanner::hemisphere_hint(&s[span.clone()]) {
+                        Some(hemisphere) => hemisphere.is_lat() == is_first,
+                        None => true,
+                    }
+                }
+            };
+            let kind = match (kind, first_is_lat) {
+                (CoordParseErrorKind::LatParseError, false) => CoordParseErrorKind::LonParseError,
+                (CoordParseErrorKind::LonParseError, false) => CoordParseErrorKind::LatParseError,
+                (kind, _) => kind,
+            };
+            (kind, span, closest)
+        })?;
+
+        let lat_first = match options.axis_order {
+            AxisOrder::LatLon => true,
+            AxisOrder::LonLat => false,
+            AxisOrder::Auto => lat_first(s, &scanned).ok_or((
+                CoordParseErrorKind::AmbiguousAxisOrder,
+                0..s.len(),
+                None,
+            ))?,
+        };
+        let scanner::Scanned {
+            first,
+            first_span,
+            second,
+            second_span,
+        } = scanned;
+        let (lat, lat_span, lon, lon_span) = if lat_first {
+            (first, first_span, second, second_span)
+        } else {
+            (second, second_span, first, first_span)
+        };
+
+        if (options.check_range || options.axis_order != AxisOrder::LatLon) && !is_valid_lat(lat) {
+            return Err((CoordParseErrorKind::LatOutOfRange, lat_span, None));
+        }
+        if options.check_range && !is_valid_lon(lon) {
+            return Err((CoordParseErrorKind::LonOutOfRange, lon_span, None));
+        }
+
+        Ok(Coordinate { lat, lon })
+    }
+
+    /// 按指定的格式通过字符串创建Coordinate，经度和纬度都必须符合该格式
+    ///
+    /// # 参数
+    ///
+    /// - `s` - 坐标字符串
+    /// - `format` - 坐标格式
+    ///
+    /// # 返回值
+    ///
+    /// 坐标，不符合指定格式时返回错误
+    pub fn parse_as(s: &str, format: CoordFormat) -> Result<Self, CoordParseError> {
+        Coordinate::parse_with(
+            s,
+            &ParseOptions {
+                format: Some(format),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// 按指定格式输出坐标
+    ///
+    /// # 参数
+    ///
+    /// - `format` - 坐标格式
+    ///
+    /// # 返回值
+    ///
+    /// 实现了`Display`的输出对象，可以用`precision`指定精度
+    pub fn display(&self, format: CoordFormat) -> CoordDisplay {
+        CoordDisplay::new(*self, format)
+    }
+
     /// 纬度值的度部分
     pub fn lat_d(&self) -> i32 {
         self.lat.trunc() as i32
@@ -137,6 +451,7 @@ impl Coordinate {
     }
 
     /// 纬度值的秒部分（取整值）
+    #[deprecated(note = "use `lat_dms`")]
     pub fn lat_s(&self) -> f64 {
         (60.0
             * ((60.0 * self.lat.abs() - self.lat.abs().trunc())
@@ -144,6 +459,7 @@ impl Coordinate {
         .round()
     }
     /// 纬度值的秒部分（取整值）
+    #[deprecated(note = "use `lon_dms`")]
     pub fn lon_s(&self) -> f64 {
         (60.0
             * ((60.0 * self.lon.abs() - self.lon.abs().trunc())
@@ -152,22 +468,26 @@ impl Coordinate {
     }
 
     /// 纬度值的秒部分（完全值）
+    #[deprecated(note = "use `lat_dms`")]
     pub fn lat_sp(&self) -> f64 {
         60.0 * ((60.0 * self.lat.abs() - self.lat.abs().trunc())
             - (60.0 * self.lat.abs() - self.lat.abs().trunc()).trunc())
     }
     /// 经度值的秒部分（完全值）
+    #[deprecated(note = "use `lon_dms`")]
     pub fn lon_sp(&self) -> f64 {
         60.0 * ((60.0 * self.lon.abs() - self.lon.abs().trunc())
             - (60.0 * self.lon.abs() - self.lon.abs().trunc()).trunc())
     }
 
     /// 纬度值的分部分（包含秒，小数形式）
+    #[deprecated(note = "use `lat_dms`")]
     pub fn lat_md(&self) -> f64 {
         self.lat.fract() * 60.0
     }
 
     /// 经度值的分部分（包含秒，小数形式）
+    #[deprecated(note = "use `lon_dms`")]
     pub fn lon_md(&self) -> f64 {
         self.lon.fract() * 60.0
     }
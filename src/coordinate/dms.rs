@@ -0,0 +1,212 @@
+//! 度分秒
+
+use super::{CoordRangeError, Coordinate};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// 半球
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy, Clone)]
+pub enum Hemisphere {
+    /// 北纬
+    N,
+    /// 南纬
+    S,
+    /// 东经
+    E,
+    /// 西经
+    W,
+}
+
+impl Hemisphere {
+    /// 对应的符号，北纬和东经为正
+    pub fn sign(&self) -> f64 {
+        match self {
+            Hemisphere::N | Hemisphere::E => 1.0,
+            Hemisphere::S | Hemisphere::W => -1.0,
+        }
+    }
+
+    /// 是否表示纬度
+    pub fn is_lat(&self) -> bool {
+        matches!(self, Hemisphere::N | Hemisphere::S)
+    }
+}
+
+impl Display for Hemisphere {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// 由度分秒创建坐标时可能出现的错误类型
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum DmsError {
+    /// 半球与经纬度不对应
+    WrongHemisphere(Hemisphere),
+    /// 分不在[0, 60)范围内
+    MinutesOutOfRange(u32),
+    /// 秒不在[0, 60)范围内
+    SecondsOutOfRange(f64),
+    /// 换算后超出经纬度范围
+    OutOfRange(CoordRangeError),
+}
+
+impl Display for DmsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DmsError::WrongHemisphere(h) => write!(f, "unexpected hemisphere {}", h),
+            DmsError::MinutesOutOfRange(m) => write!(f, "minutes {} out of range [0, 60)", m),
+            DmsError::SecondsOutOfRange(s) => write!(f, "seconds {} out of range [0, 60)", s),
+            DmsError::OutOfRange(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for DmsError {}
+
+/// 度分秒形式的纬度或经度，度、分、秒均为非负数，符号由半球表示
+#[derive(Debug, PartialEq, Serialize, Deserialize, Copy, Clone)]
+pub struct Dms {
+    /// 半球
+    pub hemisphere: Hemisphere,
+    /// 度
+    pub degrees: u32,
+    /// 分，范围为[0, 60)
+    pub minutes: u32,
+    /// 秒，范围为[0, 60)
+    pub seconds: f64,
+}
+
+impl Dms {
+    fn from_value(value: f64, hemisphere: Hemisphere) -> Self {
+        let abs = value.abs();
+        let degrees = abs.trunc();
+        let minutes = ((abs - degrees) * 60.0).trunc();
+        let seconds = ((abs - degrees) * 60.0 - minutes) * 60.0;
+        // 浮点误差可能使秒等于60，此时进位
+        Dms {
+            hemisphere,
+            degrees: degrees as u32,
+            minutes: minutes as u32,
+            seconds: seconds.min(60.0),
+        }
+        .carry()
+    }
+
+    /// 由纬度值创建
+    pub fn from_lat(lat: f64) -> Self {
+        Dms::from_value(
+            lat,
+            if lat < 0.0 {
+                Hemisphere::S
+            } else {
+                Hemisphere::N
+            },
+        )
+    }
+
+    /// 由经度值创建
+    pub fn from_lon(lon: f64) -> Self {
+        Dms::from_value(
+            lon,
+            if lon < 0.0 {
+                Hemisphere::W
+            } else {
+                Hemisphere::E
+            },
+        )
+    }
+
+    /// 秒达到60时向分进位，分达到60时向度进位
+    fn carry(mut self) -> Self {
+        if self.seconds >= 60.0 {
+            self.seconds -= 60.0;
+            self.minutes += 1;
+        }
+        if self.minutes >= 60 {
+            self.minutes -= 60;
+            self.degrees += 1;
+        }
+        self
+    }
+
+    /// 十进制度数，南纬和西经为负
+    pub fn to_degrees(&self) -> f64 {
+        (self.degrees as f64 + self.minutes as f64 * (1.0 / 60.0) + self.seconds * (1.0 / 3600.0))
+            * self.hemisphere.sign()
+    }
+
+    /// 将秒舍入到指定的小数位数
+    ///
+    /// # 参数
+    ///
+    /// - `precision` - 秒的小数位数
+    ///
+    /// # 返回值
+    ///
+    /// 舍入后的度分秒，秒舍入到60时向分和度进位
+    pub fn round(&self, precision: usize) -> Self {
+        let scale = 10f64.powi(precision as i32);
+        Dms {
+            seconds: (self.seconds * scale).round() / scale,
+            ..*self
+        }
+        .carry()
+    }
+}
+
+impl Display for Dms {
+    /// 以`DD°MM'SS.S"N`格式输出，可以用`{:.2}`指定秒的小数位数（默认为0）
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let dms = self.round(precision);
+        let width = if precision == 0 { 2 } else { precision + 3 };
+        write!(
+            f,
+            "{}°{:02}'{:0width$.precision$}\"{}",
+            dms.degrees,
+            dms.minutes,
+            dms.seconds,
+            dms.hemisphere,
+            width = width,
+            precision = precision
+        )
+    }
+}
+
+impl Coordinate {
+    /// 纬度的度分秒
+    pub fn lat_dms(&self) -> Dms {
+        Dms::from_lat(self.lat)
+    }
+
+    /// 经度的度分秒
+    pub fn lon_dms(&self) -> Dms {
+        Dms::from_lon(self.lon)
+    }
+
+    /// 通过度分秒创建坐标
+    ///
+    /// # 参数
+    ///
+    /// - `lat` - 纬度的度分秒，半球为`N`或`S`
+    /// - `lon` - 经度的度分秒，半球为`E`或`W`
+    ///
+    /// # 返回值
+    ///
+    /// 坐标，半球不对应、分秒不在[0, 60)范围内或超出经纬度范围时返回错误
+    pub fn from_dms(lat: Dms, lon: Dms) -> Result<Self, DmsError> {
+        for (dms, is_lat) in [(lat, true), (lon, false)] {
+            if dms.hemisphere.is_lat() != is_lat {
+                return Err(DmsError::WrongHemisphere(dms.hemisphere));
+            }
+            if dms.minutes >= 60 {
+                return Err(DmsError::MinutesOutOfRange(dms.minutes));
+            }
+            if !(0.0..60.0).contains(&dms.seconds) {
+                return Err(DmsError::SecondsOutOfRange(dms.seconds));
+            }
+        }
+        Coordinate::new(lat.to_degrees(), lon.to_degrees()).map_err(DmsError::OutOfRange)
+    }
+}
@@ -0,0 +1,106 @@
+//! 从文本中提取多个坐标
+
+use super::{Coordinate, ParseOptions};
+use std::ops::Range;
+
+/// 一个坐标最多由多少个单词组成，例如`40° 30' 36" N 116° 30' 36" E`
+const MAX_WORDS: usize = 8;
+
+/// 按分隔符把文本拆分为单词
+///
+/// 空白、`,`、`;`（包括全角形式）总是分隔符；`-`、`–`出现在单词中间时是分隔符，
+/// 出现在单词开头时是负号。`to`等其他单词不会被当作坐标的一部分
+fn words(s: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (i, c) in s.char_indices() {
+        let is_separator = match c {
+            ',' | ';' | '，' | '；' => true,
+            '-' | '–' => start.is_some(),
+            c => c.is_whitespace(),
+        };
+        match (is_separator, start) {
+            (true, Some(begin)) => {
+                words.push(begin..i);
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        words.push(begin..s.len());
+    }
+
+    words
+}
+
+/// 文本中的坐标及其字节范围，由`Coordinate::find_iter`创建
+#[derive(Debug, Clone)]
+pub struct CoordMatches<'a> {
+    text: &'a str,
+    options: ParseOptions,
+    words: Vec<Range<usize>>,
+    next: usize,
+}
+
+impl Iterator for CoordMatches<'_> {
+    type Item = (Coordinate, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < self.words.len() {
+            let start = self.words[self.next].start;
+            let count = MAX_WORDS.min(self.words.len() - self.next);
+
+            // 从最少的单词开始尝试，以免把相邻的两个坐标合在一起
+            for n in 1..=count {
+                let span = start..self.words[self.next + n - 1].end;
+                if let Ok(coord) = Coordinate::try_parse(&self.text[span.clone()], &self.options) {
+                    self.next += n;
+                    return Some((coord, span));
+                }
+            }
+            self.next += 1;
+        }
+        None
+    }
+}
+
+impl Coordinate {
+    /// 查找文本中的所有坐标
+    ///
+    /// 坐标之间可以用空白、`-`、`,`、`;`或`to`等单词分隔，无法处理的部分会被跳过
+    ///
+    /// # 参数
+    ///
+    /// - `s` - 包含坐标的文本，例如`400000N1160000E-401000N1162000E`
+    /// - `options` - 解析每个坐标时使用的选项
+    ///
+    /// # 返回值
+    ///
+    /// 按出现顺序返回坐标及其在文本中的字节范围的迭代器
+    pub fn find_iter<'a>(s: &'a str, options: &ParseOptions) -> CoordMatches<'a> {
+        CoordMatches {
+            text: s,
+            options: *options,
+            words: words(s),
+            next: 0,
+        }
+    }
+
+    /// 提取文本中的所有坐标，可以直接用于`region::is_point_in_area`
+    ///
+    /// # 参数
+    ///
+    /// - `s` - 包含坐标的文本
+    ///
+    /// # 返回值
+    ///
+    /// 按出现顺序排列的坐标，无法处理的部分会被跳过
+    pub fn parse_many(s: &str) -> Vec<Coordinate> {
+        Coordinate::find_iter(s, &ParseOptions::default())
+            .map(|(coord, _)| coord)
+            .collect()
+    }
+}
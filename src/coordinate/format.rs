@@ -0,0 +1,343 @@
+//! 坐标格式
+
+use super::mgrs::MGRS_MAX_PRECISION;
+use super::{Coordinate, Dms, GridError};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// 坐标格式，对应`Coordinate::from_str`能够处理的各种单个数值格式，以及同时表示经纬度的网格坐标
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy, Clone)]
+pub enum CoordFormat {
+    /// `[NSEW]DD(D)MM.M`，例如`N4030.6E11630.6`
+    DegreeMinute,
+    /// `[NSEW]DD(D)MMSS.S`，例如`N403036.0E1163036.0`
+    DegreeMinuteSecondDecimal,
+    /// `[NSEW]DD(D)MMSSCC`（ARINC 424），例如`N40303600E116303600`
+    Arinc424,
+    /// `[NSEW]DD(D)MMSS`，例如`N403036E1163036`
+    DegreeMinuteSecond,
+    /// `[NSEW]DDD.MM.SS.SSS`（EuroScope），例如`N040.30.36.000 E116.30.36.000`
+    EuroScope,
+    /// `[NSEW] DD(D) MM.M`（PMDG），例如`N 40 30.6 E 116 30.6`
+    Pmdg,
+    /// `[+-]DD(D).D`，例如`40.51,116.51`
+    Decimal,
+    /// `DD(D)[MM[SS]][.S][NSEW]`（ICAO Doc 4444），例如`403036N1163036E`
+    Icao,
+    /// `[NSEW]DD°[MM'[SS"]][NSEW]`，例如`40°30'36"N 116°30'36"E`、`北纬40°30′36″ 东经116°30′36″`
+    Symbolic,
+    /// `[NSEW]D(DD).D`，例如`N40.51 E116.51`
+    HemisphereDecimal,
+    /// UTM/UPS网格坐标（带号、半球、东向和北向坐标），例如`50N 448251 4419478`
+    Utm,
+    /// MGRS网格参考，例如`50SMK4873229151`
+    Mgrs,
+}
+
+impl CoordFormat {
+    /// 所有格式，按`Coordinate::from_str`尝试的顺序排列
+    pub const ALL: [CoordFormat; 12] = [
+        CoordFormat::DegreeMinute,
+        CoordFormat::DegreeMinuteSecondDecimal,
+        CoordFormat::Arinc424,
+        CoordFormat::DegreeMinuteSecond,
+        CoordFormat::EuroScope,
+        CoordFormat::Pmdg,
+        CoordFormat::Decimal,
+        CoordFormat::Icao,
+        CoordFormat::Symbolic,
+        CoordFormat::HemisphereDecimal,
+        CoordFormat::Utm,
+        CoordFormat::Mgrs,
+    ];
+
+    /// 格式说明
+    pub fn description(&self) -> &'static str {
+        match self {
+            CoordFormat::DegreeMinute => "[NSEW]DD(D)MM.M",
+            CoordFormat::DegreeMinuteSecondDecimal => "[NSEW]DD(D)MMSS.S",
+            CoordFormat::Arinc424 => "[NSEW]DD(D)MMSSCC",
+            CoordFormat::DegreeMinuteSecond => "[NSEW]DD(D)MMSS",
+            CoordFormat::EuroScope => "[NSEW]DDD.MM.SS.SSS",
+            CoordFormat::Pmdg => "[NSEW] DD(D) MM.M",
+            CoordFormat::Decimal => "[+-]DD(D).D",
+            CoordFormat::Icao => "DD(D)[MM[SS]][.S][NSEW]",
+            CoordFormat::Symbolic => "[NSEW]DD°[MM'[SS\"]][NSEW]",
+            CoordFormat::HemisphereDecimal => "[NSEW]D(DD).D",
+            CoordFormat::Utm => "ZZ[NS] E N",
+            CoordFormat::Mgrs => "ZZBSS[E..N..]",
+        }
+    }
+}
+
+impl CoordFormat {
+    /// 默认精度（最后一项的小数位数），UTM为米的小数位数，MGRS为东向和北向坐标各自的位数
+    pub fn default_precision(&self) -> usize {
+        match self {
+            CoordFormat::DegreeMinute | CoordFormat::Pmdg => 4,
+            CoordFormat::DegreeMinuteSecondDecimal | CoordFormat::Symbolic => 2,
+            CoordFormat::Decimal | CoordFormat::HemisphereDecimal => 6,
+            CoordFormat::EuroScope => 3,
+            CoordFormat::Arinc424
+            | CoordFormat::DegreeMinuteSecond
+            | CoordFormat::Icao
+            | CoordFormat::Utm => 0,
+            CoordFormat::Mgrs => MGRS_MAX_PRECISION,
+        }
+    }
+}
+
+/// 精度上限，避免换算为整数时溢出
+const MAX_PRECISION: usize = 12;
+
+/// 按指定格式输出坐标，由`Coordinate::display`创建
+///
+/// 精度表示最后一项（度、分或秒）的小数位数，未指定时使用格式化参数中的精度（如`{:.3}`），
+/// 都没有时使用`CoordFormat::default_precision`。
+/// ARINC 424、EuroScope和`[NSEW]DD(D)MMSS`格式的位数固定，忽略精度。
+/// UTM的精度为米的小数位数，MGRS的精度为东向和北向坐标各自的位数（最多5位）。
+///
+/// 输出的字符串总能被`Coordinate::parse_as`按同一格式解析，
+/// 误差不超过最后一项的半个单位，再次输出时结果相同。
+/// MGRS解析为方格中心，误差不超过半个方格的对角线；网格坐标位于带边缘时，再次输出可能落在相邻的带。
+/// 坐标超出范围时UTM和MGRS格式无法输出，改为按`CoordFormat::Decimal`的默认精度输出，
+/// 需要区分时使用`try_format`
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct CoordDisplay {
+    coord: Coordinate,
+    format: CoordFormat,
+    precision: Option<usize>,
+}
+
+impl CoordDisplay {
+    pub(crate) fn new(coord: Coordinate, format: CoordFormat) -> Self {
+        CoordDisplay {
+            coord,
+            format,
+            precision: None,
+        }
+    }
+
+    /// 指定精度
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// 输出为字符串，网格坐标无法输出时返回错误而不是改用十进制格式
+    ///
+    /// # 返回值
+    ///
+    /// 与`to_string`相同的字符串，UTM和MGRS格式下坐标超出范围时返回错误
+    pub fn try_format(&self) -> Result<String, GridError> {
+        match self.format {
+            CoordFormat::Utm => {
+                self.coord.to_utm()?;
+            }
+            CoordFormat::Mgrs => {
+                let precision = self.precision.unwrap_or(self.format.default_precision());
+                self.coord.to_mgrs(precision)?;
+            }
+            _ => {}
+        }
+        Ok(self.to_string())
+    }
+
+    /// 网格坐标无法输出时使用的十进制格式
+    fn fallback(&self) -> CoordDisplay {
+        CoordDisplay::new(self.coord, CoordFormat::Decimal)
+            .precision(CoordFormat::Decimal.default_precision())
+    }
+}
+
+/// 将数值的绝对值换算为整数，单位为原单位的`1 / (per_unit * 10^precision)`。
+/// 舍入在换算时完成，之后拆分度分时只有整数运算，不会出现60分
+fn to_units(value: f64, per_unit: u64, precision: usize) -> u64 {
+    (value.abs() * per_unit as f64 * 10f64.powi(precision as i32)).round() as u64
+}
+
+/// 输出小数部分，精度为0时只输出小数点
+fn write_fraction(f: &mut Formatter<'_>, fraction: u64, precision: usize) -> fmt::Result {
+    if precision == 0 {
+        write!(f, ".")
+    } else {
+        write!(f, ".{:0width$}", fraction, width = precision)
+    }
+}
+
+/// 输出单个数值
+fn write_value(
+    f: &mut Formatter<'_>,
+    value: f64,
+    is_lat: bool,
+    format: CoordFormat,
+    precision: usize,
+) -> fmt::Result {
+    let hemisphere = match (is_lat, value.is_sign_negative()) {
+        (true, false) => 'N',
+        (true, true) => 'S',
+        (false, false) => 'E',
+        (false, true) => 'W',
+    };
+    let width = if is_lat { 2 } else { 3 };
+    let scale = 10u64.pow(precision as u32);
+
+    match format {
+        CoordFormat::DegreeMinute | CoordFormat::Pmdg => {
+            let units = to_units(value, 60, precision);
+            let (d, m) = (units / (60 * scale), units % (60 * scale));
+            if format == CoordFormat::Pmdg {
+                write!(
+                    f,
+                    "{} {:0width$} {:02}",
+                    hemisphere,
+                    d,
+                    m / scale,
+                    width = width
+                )?;
+            } else {
+                write!(
+                    f,
+                    "{}{:0width$}{:02}",
+                    hemisphere,
+                    d,
+                    m / scale,
+                    width = width
+                )?;
+            }
+            write_fraction(f, m % scale, precision)
+        }
+        CoordFormat::DegreeMinuteSecondDecimal
+        | CoordFormat::DegreeMinuteSecond
+        | CoordFormat::Arinc424
+        | CoordFormat::EuroScope
+        | CoordFormat::Icao
+        | CoordFormat::Symbolic => {
+            let precision = match format {
+                CoordFormat::DegreeMinuteSecond => 0,
+                CoordFormat::Arinc424 => 2,
+                CoordFormat::EuroScope => 3,
+                _ => precision,
+            };
+            let dms = if is_lat {
+                Dms::from_lat(value)
+            } else {
+                Dms::from_lon(value)
+            }
+            .round(precision);
+            let (hemisphere, d, m) = (dms.hemisphere, dms.degrees, dms.minutes);
+            let scale = 10u64.pow(precision as u32);
+            let s = to_units(dms.seconds, 1, precision);
+            let (s, fraction) = (s / scale, s % scale);
+
+            match format {
+                CoordFormat::DegreeMinuteSecondDecimal => {
+                    write!(
+                        f,
+                        "{}{:0width$}{:02}{:02}",
+                        hemisphere,
+                        d,
+                        m,
+                        s,
+                        width = width
+                    )?;
+                    write_fraction(f, fraction, precision)
+                }
+                CoordFormat::DegreeMinuteSecond => {
+                    write!(
+                        f,
+                        "{}{:0width$}{:02}{:02}",
+                        hemisphere,
+                        d,
+                        m,
+                        s,
+                        width = width
+                    )
+                }
+                CoordFormat::Arinc424 => write!(
+                    f,
+                    "{}{:0width$}{:02}{:02}{:02}",
+                    hemisphere,
+                    d,
+                    m,
+                    s,
+                    fraction,
+                    width = width
+                ),
+                CoordFormat::EuroScope => {
+                    write!(
+                        f,
+                        "{}{:03}.{:02}.{:02}.{:03}",
+                        hemisphere, d, m, s, fraction
+                    )
+                }
+                CoordFormat::Icao => {
+                    write!(f, "{:0width$}{:02}{:02}", d, m, s, width = width)?;
+                    if precision > 0 {
+                        write_fraction(f, fraction, precision)?;
+                    }
+                    write!(f, "{}", hemisphere)
+                }
+                _ => {
+                    write!(f, "{}°{:02}'{:02}", d, m, s)?;
+                    if precision > 0 {
+                        write_fraction(f, fraction, precision)?;
+                    }
+                    write!(f, "\"{}", hemisphere)
+                }
+            }
+        }
+        CoordFormat::Decimal => {
+            let sign = if value.is_sign_negative() { "-" } else { "" };
+            let units = to_units(value, 1, precision);
+            write!(f, "{}{:02}", sign, units / scale)?;
+            write_fraction(f, units % scale, precision)
+        }
+        CoordFormat::HemisphereDecimal => {
+            let units = to_units(value, 1, precision);
+            write!(f, "{}{}", hemisphere, units / scale)?;
+            write_fraction(f, units % scale, precision)
+        }
+        CoordFormat::Utm | CoordFormat::Mgrs => unreachable!("网格坐标整体输出"),
+    }
+}
+
+impl Display for CoordDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = self
+            .precision
+            .or(f.precision())
+            .unwrap_or(self.format.default_precision())
+            .min(MAX_PRECISION);
+
+        // 网格坐标不能输出时（坐标超出范围）改用十进制格式
+        match self.format {
+            CoordFormat::Utm => {
+                return match self.coord.to_utm() {
+                    Ok(utm) => write!(f, "{:.*}", precision, utm),
+                    Err(_) => self.fallback().fmt(f),
+                };
+            }
+            CoordFormat::Mgrs => {
+                return match self.coord.to_mgrs(precision) {
+                    Ok(mgrs) => f.write_str(&mgrs),
+                    Err(_) => self.fallback().fmt(f),
+                };
+            }
+            _ => {}
+        }
+
+        let separator = match self.format {
+            CoordFormat::EuroScope
+            | CoordFormat::Pmdg
+            | CoordFormat::Symbolic
+            | CoordFormat::HemisphereDecimal => " ",
+            CoordFormat::Decimal => ",",
+            _ => "",
+        };
+
+        write_value(f, self.coord.lat, true, self.format, precision)?;
+        write!(f, "{}", separator)?;
+        write_value(f, self.coord.lon, false, self.format, precision)
+    }
+}
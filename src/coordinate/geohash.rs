@@ -0,0 +1,277 @@
+//! Geohash编码
+
+use super::{normalize_lon, CoordRangeError, Coordinate};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Geohash使用的base32字母表
+const ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// 最大长度，12位时单元格约为3.7厘米×1.9厘米
+pub const GEOHASH_MAX_PRECISION: usize = 12;
+
+/// 处理Geohash时可能出现的错误
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum GeohashError {
+    /// 不是Geohash字母表中的字符
+    InvalidCharacter(char),
+    /// 长度不在[1, 12]范围内
+    InvalidLength(usize),
+}
+
+impl Display for GeohashError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GeohashError::InvalidCharacter(c) => write!(f, "invalid geohash character {:?}", c),
+            GeohashError::InvalidLength(len) => {
+                write!(f, "geohash length {} out of range [1, 12]", len)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeohashError {}
+
+/// 相邻单元格的方向
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy, Clone)]
+pub enum Direction {
+    /// 北
+    North,
+    /// 东北
+    NorthEast,
+    /// 东
+    East,
+    /// 东南
+    SouthEast,
+    /// 南
+    South,
+    /// 西南
+    SouthWest,
+    /// 西
+    West,
+    /// 西北
+    NorthWest,
+}
+
+impl Direction {
+    /// 所有方向，从北开始顺时针排列
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// 纬度和经度方向的单元格偏移
+    fn offset(&self) -> (f64, f64) {
+        match self {
+            Direction::North => (1.0, 0.0),
+            Direction::NorthEast => (1.0, 1.0),
+            Direction::East => (0.0, 1.0),
+            Direction::SouthEast => (-1.0, 1.0),
+            Direction::South => (-1.0, 0.0),
+            Direction::SouthWest => (-1.0, -1.0),
+            Direction::West => (0.0, -1.0),
+            Direction::NorthWest => (1.0, -1.0),
+        }
+    }
+}
+
+/// Geohash单元格
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Clone)]
+pub struct Geohash(String);
+
+/// Geohash单元格的范围
+#[derive(Debug, PartialEq, Serialize, Deserialize, Copy, Clone)]
+pub struct GeohashBounds {
+    /// 南边界纬度
+    pub south: f64,
+    /// 西边界经度
+    pub west: f64,
+    /// 北边界纬度
+    pub north: f64,
+    /// 东边界经度
+    pub east: f64,
+}
+
+impl GeohashBounds {
+    /// 中心点
+    pub fn center(&self) -> Coordinate {
+        Coordinate {
+            lat: (self.south + self.north) / 2.0,
+            lon: (self.west + self.east) / 2.0,
+        }
+    }
+
+    /// 坐标是否在范围内（包括南边界和西边界）
+    pub fn contains(&self, coord: &Coordinate) -> bool {
+        (self.south..self.north).contains(&coord.lat) && (self.west..self.east).contains(&coord.lon)
+    }
+}
+
+/// 经度和纬度各自的位数，经度在前交替排列
+fn bits(precision: usize) -> (u32, u32) {
+    let total = 5 * precision as u32;
+    (total.div_ceil(2), total / 2)
+}
+
+/// 将数值量化为`bits`位的序号
+fn quantize(value: f64, min: f64, max: f64, bits: u32) -> u64 {
+    let cells = (1u64 << bits) as f64;
+    (((value - min) / (max - min) * cells).floor() as u64).min((1u64 << bits) - 1)
+}
+
+impl Geohash {
+    /// 编码坐标
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - 坐标，经度可以超出[-180, 180]范围
+    /// - `precision` - 长度，1~12，超出范围时取最接近的值
+    ///
+    /// # 返回值
+    ///
+    /// 包含该坐标的单元格，纬度超出[-90, 90]或经度不是有限值时返回错误
+    pub fn encode(coord: &Coordinate, precision: usize) -> Result<Self, CoordRangeError> {
+        let Coordinate { lat, lon } = coord.normalize()?;
+        let precision = precision.clamp(1, GEOHASH_MAX_PRECISION);
+        let (lon_bits, lat_bits) = bits(precision);
+        let lon = quantize(lon, -180.0, 180.0, lon_bits);
+        let lat = quantize(lat, -90.0, 90.0, lat_bits);
+
+        // 从最高位开始，经度和纬度交替，经度在前
+        let mut hash = 0u64;
+        for i in 0..lon_bits + lat_bits {
+            let bit = if i % 2 == 0 {
+                lon >> (lon_bits - 1 - i / 2) & 1
+            } else {
+                lat >> (lat_bits - 1 - i / 2) & 1
+            };
+            hash = hash << 1 | bit;
+        }
+
+        let s = (0..precision)
+            .rev()
+            .map(|i| ALPHABET[(hash >> (5 * i) & 31) as usize] as char)
+            .collect();
+        Ok(Geohash(s))
+    }
+
+    /// Geohash字符串
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 长度
+    pub fn precision(&self) -> usize {
+        self.0.len()
+    }
+
+    /// 单元格的范围
+    pub fn bounds(&self) -> GeohashBounds {
+        let (lon_bits, lat_bits) = bits(self.precision());
+        let (mut lon, mut lat) = (0u64, 0u64);
+        for (i, c) in self.0.bytes().enumerate() {
+            let value = ALPHABET.iter().position(|&a| a == c).unwrap_or(0) as u64;
+            for j in (0..5).rev() {
+                let bit = value >> j & 1;
+                if (5 * i + 4 - j) % 2 == 0 {
+                    lon = lon << 1 | bit;
+                } else {
+                    lat = lat << 1 | bit;
+                }
+            }
+        }
+
+        let lon_size = 360.0 / (1u64 << lon_bits) as f64;
+        let lat_size = 180.0 / (1u64 << lat_bits) as f64;
+        GeohashBounds {
+            south: -90.0 + lat as f64 * lat_size,
+            west: -180.0 + lon as f64 * lon_size,
+            north: -90.0 + (lat + 1) as f64 * lat_size,
+            east: -180.0 + (lon + 1) as f64 * lon_size,
+        }
+    }
+
+    /// 单元格的中心点
+    pub fn center(&self) -> Coordinate {
+        self.bounds().center()
+    }
+
+    /// 相邻的同级单元格，经度方向跨越180°经线时回绕
+    ///
+    /// # 参数
+    ///
+    /// - `direction` - 方向
+    ///
+    /// # 返回值
+    ///
+    /// 相邻的单元格，越过南北极时为`None`
+    pub fn neighbor(&self, direction: Direction) -> Option<Geohash> {
+        let bounds = self.bounds();
+        let center = bounds.center();
+        let (dlat, dlon) = direction.offset();
+        let lat = center.lat + dlat * (bounds.north - bounds.south);
+        if !(-90.0..=90.0).contains(&lat) {
+            return None;
+        }
+        let lon = normalize_lon(center.lon + dlon * (bounds.east - bounds.west));
+        Geohash::encode(&Coordinate { lat, lon }, self.precision()).ok()
+    }
+
+    /// 周围的8个同级单元格，按`Direction::ALL`的顺序排列，越过南北极的为`None`
+    pub fn neighbors(&self) -> [Option<Geohash>; 8] {
+        Direction::ALL.map(|direction| self.neighbor(direction))
+    }
+}
+
+impl FromStr for Geohash {
+    type Err = GeohashError;
+
+    /// 处理Geohash字符串，不区分大小写
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let len = s.chars().count();
+        if !(1..=GEOHASH_MAX_PRECISION).contains(&len) {
+            return Err(GeohashError::InvalidLength(len));
+        }
+        let s = s.to_ascii_lowercase();
+        if let Some(c) = s
+            .chars()
+            .find(|&c| !c.is_ascii() || !ALPHABET.contains(&(c as u8)))
+        {
+            return Err(GeohashError::InvalidCharacter(c));
+        }
+        Ok(Geohash(s))
+    }
+}
+
+impl Display for Geohash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Coordinate {
+    /// 编码为Geohash，参见`Geohash::encode`
+    pub fn to_geohash(&self, precision: usize) -> Result<String, CoordRangeError> {
+        Geohash::encode(self, precision).map(|hash| hash.0)
+    }
+
+    /// 通过Geohash创建坐标
+    ///
+    /// # 参数
+    ///
+    /// - `s` - Geohash字符串
+    ///
+    /// # 返回值
+    ///
+    /// 单元格的中心点，字符串无效时返回错误
+    pub fn from_geohash(s: &str) -> Result<Self, GeohashError> {
+        s.parse::<Geohash>().map(|hash| hash.center())
+    }
+}
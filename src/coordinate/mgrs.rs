@@ -0,0 +1,201 @@
+//! MGRS网格参考
+
+use super::utm::{utm_projection, GridError, Utm, UTM_FALSE_NORTHING};
+use super::Coordinate;
+
+/// 精度上限，5位时精确到1米
+pub(crate) const MGRS_MAX_PRECISION: usize = 5;
+
+/// 100千米方格的边长
+const TILE: f64 = 100_000.0;
+/// UTM纬度带，从80°S开始，每8度一个，`X`带为72°N~84°N
+const BANDS: &[u8] = b"CDEFGHJKLMNPQRSTUVWX";
+/// UTM方格的列字母，按`(带号 - 1) % 3`选择
+const UTM_COLS: [&[u8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+/// UTM方格的行字母，偶数带偏移5个字母
+const UTM_ROWS: &[u8] = b"ABCDEFGHJKLMNPQRSTUV";
+/// UPS纬度带：南极西半部、南极东半部、北极西半部、北极东半部
+const UPS_BANDS: &[u8] = b"ABYZ";
+/// UPS方格的列字母，与`UPS_BANDS`对应
+const UPS_COLS: [&[u8]; 4] = [b"JKLPQRSTUXYZ", b"ABCFGHJKLPQR", b"RSTUXYZ", b"ABCFGHJ"];
+/// UPS方格的行字母：南极、北极
+const UPS_ROWS: [&[u8]; 2] = [b"ABCDEFGHJKLMNPQRSTUVWXYZ", b"ABCDEFGHJKLMNP"];
+/// UPS第一列（行）方格的序号：南极、北极
+const UPS_MIN_TILE: [u32; 2] = [8, 13];
+/// UPS原点所在方格的序号
+const UPS_ORIGIN_TILE: u32 = 20;
+
+/// 方格内的东向或北向坐标按精度截断后的数字
+fn digits(value: f64, precision: usize) -> u32 {
+    let unit = 10f64.powi((MGRS_MAX_PRECISION - precision) as i32);
+    ((value.rem_euclid(TILE) / unit).floor() as u32).min(10u32.pow(precision as u32) - 1)
+}
+
+/// 查找字母在表中的位置
+fn index_of(letters: &[u8], letter: u8) -> Option<u32> {
+    letters.iter().position(|&c| c == letter).map(|i| i as u32)
+}
+
+/// 纬度带南边界所在100千米方格的北向坐标，用于确定北向坐标中省略的2000千米部分
+fn band_floor(band: usize) -> f64 {
+    let lat = band as f64 * 8.0 - 80.0;
+    let tm = utm_projection();
+    // 纬线在中央经线或带边缘处最靠南
+    let y = tm.forward(lat, 0.0).1.min(tm.forward(lat, 3.0).1);
+    let y = if band < 10 { y + UTM_FALSE_NORTHING } else { y };
+    (y / TILE).floor() * TILE
+}
+
+impl Coordinate {
+    /// 编码为MGRS网格参考
+    ///
+    /// # 参数
+    ///
+    /// - `precision` - 东向和北向坐标各自的位数，0~5，0表示只给出100千米方格，5表示精确到1米，超过5时按5处理
+    ///
+    /// # 返回值
+    ///
+    /// 不含空格的MGRS字符串，例如`50SMK4873229151`，坐标所在方格向下截断。纬度超出范围时返回错误
+    pub fn to_mgrs(&self, precision: usize) -> Result<String, GridError> {
+        let precision = precision.min(MGRS_MAX_PRECISION);
+        let utm = Utm::from_coordinate(self)?;
+        let col = (utm.easting / TILE).floor() as u32;
+        let row = (utm.northing / TILE).floor() as u32;
+
+        let mut s = String::new();
+        if utm.zone == 0 {
+            let hemisphere = utm.north as usize;
+            let east = col >= UPS_ORIGIN_TILE;
+            let band = hemisphere * 2 + east as usize;
+            let first_col = if east {
+                UPS_ORIGIN_TILE
+            } else {
+                UPS_MIN_TILE[hemisphere]
+            };
+            let col = col
+                .checked_sub(first_col)
+                .and_then(|i| UPS_COLS[band].get(i as usize));
+            let row = row
+                .checked_sub(UPS_MIN_TILE[hemisphere])
+                .and_then(|i| UPS_ROWS[hemisphere].get(i as usize));
+            let (Some(&col), Some(&row)) = (col, row) else {
+                return Err(GridError::OutOfZone);
+            };
+            s.push(UPS_BANDS[band] as char);
+            s.push(col as char);
+            s.push(row as char);
+        } else {
+            let lat = self.lat;
+            let band = (((lat + 80.0) / 8.0).floor() as usize).min(BANDS.len() - 1);
+            let col = (col as usize)
+                .checked_sub(1)
+                .and_then(|i| UTM_COLS[(utm.zone as usize - 1) % 3].get(i));
+            let Some(&col) = col else {
+                return Err(GridError::OutOfZone);
+            };
+            let offset = if utm.zone % 2 == 0 { 5 } else { 0 };
+            let row = UTM_ROWS[(row as usize + offset) % UTM_ROWS.len()];
+            s.push_str(&format!("{:02}", utm.zone));
+            s.push(BANDS[band] as char);
+            s.push(col as char);
+            s.push(row as char);
+        }
+
+        if precision > 0 {
+            s.push_str(&format!(
+                "{:0width$}{:0width$}",
+                digits(utm.easting, precision),
+                digits(utm.northing, precision),
+                width = precision
+            ));
+        }
+        Ok(s)
+    }
+
+    /// 通过MGRS网格参考创建坐标
+    ///
+    /// # 参数
+    ///
+    /// - `s` - MGRS字符串，各部分之间可以有空格，不区分大小写，例如`50SMK 4873 2915`
+    ///
+    /// # 返回值
+    ///
+    /// 精度范围内方格的中心点坐标，无法处理时返回错误
+    pub fn from_mgrs(s: &str) -> Result<Self, GridError> {
+        let s: Vec<u8> = s
+            .bytes()
+            .filter(|c| !c.is_ascii_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let zone_len = s.iter().take_while(|c| c.is_ascii_digit()).count();
+        if zone_len > 2 || s.len() < zone_len + 3 {
+            return Err(GridError::InvalidFormat);
+        }
+        let (zone, rest) = s.split_at(zone_len);
+        let (letters, numbers) = rest.split_at(3);
+        if numbers.len() % 2 != 0
+            || numbers.len() > 2 * MGRS_MAX_PRECISION
+            || !numbers.iter().all(|c| c.is_ascii_digit())
+        {
+            return Err(GridError::InvalidFormat);
+        }
+
+        let precision = numbers.len() / 2;
+        let unit = 10f64.powi((MGRS_MAX_PRECISION - precision) as i32);
+        let number = |digits: &[u8]| {
+            digits
+                .iter()
+                .fold(0.0, |value, c| value * 10.0 + (c - b'0') as f64)
+                * unit
+                + unit / 2.0
+        };
+        let (easting, northing) = numbers.split_at(precision);
+        let (easting, northing) = (number(easting), number(northing));
+
+        let utm = if zone.is_empty() {
+            let band = index_of(UPS_BANDS, letters[0]).ok_or(GridError::InvalidFormat)? as usize;
+            let hemisphere = band / 2;
+            let east = band % 2 == 1;
+            let col = index_of(UPS_COLS[band], letters[1]).ok_or(GridError::InvalidFormat)?;
+            let row = index_of(UPS_ROWS[hemisphere], letters[2]).ok_or(GridError::InvalidFormat)?;
+            let first_col = if east {
+                UPS_ORIGIN_TILE
+            } else {
+                UPS_MIN_TILE[hemisphere]
+            };
+            Utm {
+                zone: 0,
+                north: hemisphere == 1,
+                easting: (col + first_col) as f64 * TILE + easting,
+                northing: (row + UPS_MIN_TILE[hemisphere]) as f64 * TILE + northing,
+            }
+        } else {
+            let zone: u8 = std::str::from_utf8(zone)
+                .ok()
+                .and_then(|zone| zone.parse().ok())
+                .filter(|zone| (1..=60).contains(zone))
+                .ok_or(GridError::InvalidFormat)?;
+            let band = index_of(BANDS, letters[0]).ok_or(GridError::InvalidFormat)? as usize;
+            let col = index_of(UTM_COLS[(zone as usize - 1) % 3], letters[1])
+                .ok_or(GridError::InvalidFormat)?;
+            let row = index_of(UTM_ROWS, letters[2]).ok_or(GridError::InvalidFormat)?;
+            let offset = if zone % 2 == 0 { 5 } else { 0 };
+            let row = (row + UTM_ROWS.len() as u32 - offset) % UTM_ROWS.len() as u32;
+
+            // 行字母每2000千米重复一次，从纬度带的南边界开始向北查找
+            let floor = band_floor(band);
+            let mut northing = row as f64 * TILE + northing;
+            while northing < floor {
+                northing += 2_000_000.0;
+            }
+            Utm {
+                zone,
+                north: band >= 10,
+                easting: (col + 1) as f64 * TILE + easting,
+                northing,
+            }
+        };
+
+        utm.to_coordinate()
+    }
+}
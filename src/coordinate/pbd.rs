@@ -0,0 +1,195 @@
+//! 以参考点、方位和距离表示的位置（Place/Bearing/Distance）
+
+use super::Coordinate;
+use crate::algorithm::bearing::MagneticBearing;
+use crate::algorithm::position_transformation::get_colocated_coord_with;
+use crate::Ellipsoid;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// 1海里对应的米数
+const METERS_PER_NAUTICAL_MILE: f64 = 1852.0;
+/// 参考点标识的长度范围，2位为NDB，3位为VOR，5位为航路点
+const IDENT_LEN: std::ops::RangeInclusive<usize> = 2..=5;
+
+/// 处理PBD时可能出现的错误
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PbdError {
+    /// 不符合任何PBD格式
+    InvalidFormat,
+    /// 参考点标识不是2~5位字母或数字，或不以字母开头
+    InvalidIdent(String),
+    /// 方位不在[0, 360]范围内
+    BearingOutOfRange,
+    /// 距离不是非负数
+    InvalidDistance,
+    /// 查找不到参考点
+    UnknownIdent(String),
+}
+
+impl Display for PbdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PbdError::InvalidFormat => write!(f, "unrecognized place/bearing/distance"),
+            PbdError::InvalidIdent(ident) => write!(f, "invalid reference point {:?}", ident),
+            PbdError::BearingOutOfRange => write!(f, "bearing out of range [0, 360]"),
+            PbdError::InvalidDistance => write!(f, "invalid distance"),
+            PbdError::UnknownIdent(ident) => write!(f, "unknown reference point {:?}", ident),
+        }
+    }
+}
+
+impl std::error::Error for PbdError {}
+
+/// 以参考点、磁方位和距离表示的位置，例如`PEK045012`表示PEK磁方位045°、12海里处的点
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct Pbd {
+    /// 参考点标识，大写
+    pub ident: String,
+    /// 从参考点出发的磁方位，单位为度
+    pub bearing: f64,
+    /// 距离，单位为海里
+    pub distance: f64,
+}
+
+impl Pbd {
+    /// 检查各部分并创建PBD，参考点标识转为大写
+    ///
+    /// # 参数
+    ///
+    /// - `ident` - 参考点标识，2~5位字母或数字，以字母开头
+    /// - `bearing` - 磁方位，单位为度，范围[0, 360]
+    /// - `distance` - 距离，单位为海里，不能为负数
+    pub fn new(ident: &str, bearing: f64, distance: f64) -> Result<Self, PbdError> {
+        let valid_ident = IDENT_LEN.contains(&ident.len())
+            && ident.starts_with(|c: char| c.is_ascii_alphabetic())
+            && ident.chars().all(|c| c.is_ascii_alphanumeric());
+        if !valid_ident {
+            return Err(PbdError::InvalidIdent(ident.to_string()));
+        }
+        if !(0.0..=360.0).contains(&bearing) {
+            return Err(PbdError::BearingOutOfRange);
+        }
+        if !(distance >= 0.0 && distance.is_finite()) {
+            return Err(PbdError::InvalidDistance);
+        }
+        Ok(Pbd {
+            ident: ident.to_ascii_uppercase(),
+            bearing,
+            distance,
+        })
+    }
+
+    /// 由参考点的位置和磁偏角计算坐标（WGS-84）
+    ///
+    /// # 参数
+    ///
+    /// - `lookup` - 按标识查找参考点，返回参考点坐标和该处的磁偏角（东正西负）
+    ///
+    /// # 返回值
+    ///
+    /// 沿测地线计算的坐标，经度在[-180, 180)范围内，查找不到参考点时返回错误
+    pub fn resolve<F>(&self, lookup: F) -> Result<Coordinate, PbdError>
+    where
+        F: Fn(&str) -> Option<(Coordinate, f64)>,
+    {
+        self.resolve_with(lookup, &Ellipsoid::WGS84)
+    }
+
+    /// 由参考点的位置和磁偏角计算指定椭球下的坐标
+    ///
+    /// # 参数
+    ///
+    /// - `lookup` - 按标识查找参考点，返回参考点坐标和该处的磁偏角（东正西负）
+    /// - `ellipsoid` - 椭球
+    ///
+    /// # 返回值
+    ///
+    /// 沿测地线计算的坐标，经度在[-180, 180)范围内，查找不到参考点时返回错误
+    pub fn resolve_with<F>(&self, lookup: F, ellipsoid: &Ellipsoid) -> Result<Coordinate, PbdError>
+    where
+        F: Fn(&str) -> Option<(Coordinate, f64)>,
+    {
+        let (coord, mag_var) =
+            lookup(&self.ident).ok_or_else(|| PbdError::UnknownIdent(self.ident.clone()))?;
+        Ok(get_colocated_coord_with(
+            &coord,
+            MagneticBearing::new(self.bearing),
+            self.distance * METERS_PER_NAUTICAL_MILE,
+            mag_var,
+            ellipsoid,
+        ))
+    }
+}
+
+/// 处理数字，只接受ASCII数字和最多一个小数点
+fn parse_number(s: &str) -> Option<f64> {
+    let valid = !s.is_empty()
+        && s.chars().filter(|&c| c == '.').count() <= 1
+        && s.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && s.chars().any(|c| c.is_ascii_digit());
+    valid.then(|| s.parse().ok()).flatten()
+}
+
+/// 处理整数位数固定的数字
+fn parse_digits(s: &str, len: usize) -> Option<f64> {
+    (s.len() == len && s.chars().all(|c| c.is_ascii_digit()))
+        .then(|| s.parse().ok())
+        .flatten()
+}
+
+impl FromStr for Pbd {
+    type Err = PbdError;
+
+    /// 处理PBD字符串，不区分大小写，支持以下格式：
+    ///
+    /// - ICAO飞行计划格式`PEK045012`：标识后接3位磁方位和3位距离（海里）
+    /// - FMS格式`PEK/045/12`：以`/`分隔标识、磁方位和距离，方位和距离可以带小数
+    /// - FMS格式`PEK045/12`：标识后接3位磁方位，以`/`分隔距离，距离可以带小数
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if !s.is_ascii() {
+            return Err(PbdError::InvalidFormat);
+        }
+        let parts: Vec<&str> = s.split('/').collect();
+        let (ident, bearing, distance) = match parts[..] {
+            [compact] => {
+                if compact.len() <= 6 {
+                    return Err(PbdError::InvalidFormat);
+                }
+                let (ident, digits) = compact.split_at(compact.len() - 6);
+                let (bearing, distance) = digits.split_at(3);
+                (ident, parse_digits(bearing, 3), parse_digits(distance, 3))
+            }
+            [place_bearing, distance] => {
+                if place_bearing.len() <= 3 {
+                    return Err(PbdError::InvalidFormat);
+                }
+                let (ident, bearing) = place_bearing.split_at(place_bearing.len() - 3);
+                (ident, parse_digits(bearing, 3), parse_number(distance))
+            }
+            [ident, bearing, distance] => (ident, parse_number(bearing), parse_number(distance)),
+            _ => return Err(PbdError::InvalidFormat),
+        };
+        match (bearing, distance) {
+            (Some(bearing), Some(distance)) => Pbd::new(ident, bearing, distance),
+            _ => Err(PbdError::InvalidFormat),
+        }
+    }
+}
+
+impl Display for Pbd {
+    /// 整数方位和距离以ICAO格式输出，例如`PEK045012`，否则以FMS格式输出，例如`PEK/045/12.5`
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.bearing.fract() == 0.0 && self.distance.fract() == 0.0 && self.distance < 1000.0 {
+            write!(
+                f,
+                "{}{:03}{:03}",
+                self.ident, self.bearing as u32, self.distance as u32
+            )
+        } else {
+            write!(f, "{}/{:03}/{}", self.ident, self.bearing, self.distance)
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! 基于正则表达式的坐标解析实现
+//!
+//! 这是扫描器之前的实现，保留下来用于对比测试和性能测试，需要启用`regex`特性。
+
+use super::{CoordFormat, CoordParseError, CoordParseErrorKind, Coordinate};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// 单个数值可接受格式的正则表达式，与`CoordFormat::ALL`的前七项一一对应
+const SINGLE_PATTERNS: [&str; 7] = [
+    r"^([NSEW])([01]?\d{2})(\d{2}\.\d*)$",
+    r"^([NSEW])([01]?\d{2})(\d{2})(\d{2}\.\d*)$",
+    r"^([NSEW])([01]?\d{2})(\d{2})(\d{2})(\d{2})$",
+    r"^([NSEW])([01]?\d{2})(\d{2})(\d{2})$",
+    r"^([NSEW])(\d{3})\.(\d{2})\.(\d{2}\.\d{3})$",
+    r"^([NSEW]) ([01]?\d{2}) (\d{2}\.\d*)$",
+    r"^([+-]?)(\d{2,3}\.\d*)$",
+];
+
+lazy_static! {
+    ///  # 表示坐标格式的正则表达式（单个数值）
+    ///  - 第一组是正负
+    ///  - 第二组是度
+    ///  - 第三组是分
+    ///  - 第四组是秒
+    static ref RE_SINGLE: Vec<Regex> = SINGLE_PATTERNS
+        .iter()
+        .map(|re| Regex::new(re).unwrap())
+        .collect();
+
+    /// 与RE_SINGLE对应、只锚定开头的正则表达式，用于查找最接近的格式
+    static ref RE_SINGLE_PREFIX: Vec<Regex> = SINGLE_PATTERNS
+        .iter()
+        .map(|re| Regex::new(re.trim_end_matches('$')).unwrap())
+        .collect();
+
+    /// 表示单位换算时的比例
+    static ref RATIO: HashMap<usize, f64> = vec![(2, 1.0), (3, 1.0 / 60.0), (4, 1.0 / 3600.0), (5, 1.0 / 360000.0)]
+        .into_iter()
+        .collect();
+
+    /// 表示坐标格式的正则表达式（经纬度都包含）
+    static ref RE_COMBINE: Vec<Regex> = vec![
+        Regex::new(r"^([NS].*?) ?([EW].*?)$").unwrap(),  // Nxxxx.xExxxxx.x、Nxxxxxx.xExxxxxxx.x、NxxxxxxExxxxxxx、Nxxx.xx.xx.xxx Exxx.xx.xx.xxx、N xx xx.xxxxxx E xxx xx.xxxxxx
+        Regex::new(r"^(.*?),(.*?)$").unwrap(),         // xx.xxxxxxxxxxxxxx,xxx.xxxxxxxxxxxxxxx
+        Regex::new(r"^([^ ]*?) ([^ ]*?)$").unwrap()    // +xx.xxxxxxxx +xxx.xxxxxx
+    ];
+}
+
+/// 查找与输入最接近的格式（能匹配的前缀最长者）
+fn closest_format(s: &str) -> Option<CoordFormat> {
+    RE_SINGLE_PREFIX
+        .iter()
+        .zip(CoordFormat::ALL.iter())
+        .filter_map(|(r, format)| r.find(s).map(|m| (m.end(), *format)))
+        .fold(
+            None,
+            |best: Option<(usize, CoordFormat)>, (len, format)| match best {
+                Some((best_len, _)) if best_len >= len => best,
+                _ => Some((len, format)),
+            },
+        )
+        .map(|(_, format)| format)
+}
+
+/// 将输入的字符串变成f64类型的数值，无法处理时返回最接近的格式
+fn parse_single(s: &str) -> Result<f64, Option<CoordFormat>> {
+    RE_SINGLE
+        .iter()
+        .find_map(|r| r.captures(s))
+        .map(|group| {
+            let sum = (2..group.len())
+                .map(|i| {
+                    group
+                        .get(i)
+                        .and_then(|m| m.as_str().parse::<f64>().ok())
+                        .map(|num| num * RATIO.get(&i).unwrap_or(&1.0))
+                        .unwrap_or(0.0)
+                })
+                .sum::<f64>();
+            let sign = group
+                .get(1)
+                .map(|m| match m.as_str() {
+                    "S" | "W" | "-" => -1.0,
+                    _ => 1.0,
+                })
+                .unwrap_or(1.0);
+
+            sum * sign
+        })
+        .ok_or_else(|| closest_format(s))
+}
+
+/// 通过正则表达式解析坐标字符串
+///
+/// 只支持扫描器最初的七种格式，这些格式的结果与`Coordinate::from_str`一致
+pub fn parse(s: &str) -> Result<Coordinate, CoordParseError> {
+    RE_COMBINE
+        .iter()
+        .find_map(|r| r.captures(s))
+        .map(|group| {
+            let (lat, lon) = (group.get(1).unwrap(), group.get(2).unwrap());
+
+            let lat = parse_single(lat.as_str()).map_err(|closest| {
+                CoordParseError::new(
+                    CoordParseErrorKind::LatParseError,
+                    s,
+                    lat.range(),
+                    closest,
+                    None,
+                )
+            })?;
+
+            let lon = parse_single(lon.as_str()).map_err(|closest| {
+                CoordParseError::new(
+                    CoordParseErrorKind::LonParseError,
+                    s,
+                    lon.range(),
+                    closest,
+                    None,
+                )
+            })?;
+
+            Ok(Coordinate { lat, lon })
+        })
+        .unwrap_or_else(|| {
+            Err(CoordParseError::new(
+                CoordParseErrorKind::NoMatchingFormat,
+                s,
+                0..s.len(),
+                closest_format(s),
+                None,
+            ))
+        })
+}
@@ -0,0 +1,565 @@
+//! 不依赖正则表达式的坐标扫描器
+//!
+//! 接受的格式是原先正则表达式表的超集，原有格式的处理结果不变，另外接受：
+//!
+//! - ICAO Doc 4444半球标识在后的格式，例如`4030N11630E`、`403003.6N1163003.6E`
+//! - 使用度分秒符号的格式，半球标识在前或在后，例如`N40°30'36"`、`40°30′36″N`、`40°30.6'N`
+//! - 全角字符、中文半球标识和中文度分秒，例如`Ｎ４０３０３６`、`北纬40°30′36″`、`南纬40度30分36秒`
+
+use super::{CoordFormat, CoordParseErrorKind, Hemisphere};
+use std::ops::Range;
+
+/// 单个数值格式的扫描函数：成功时返回数值，失败时返回能够匹配的前缀长度
+type SingleScanner = fn(&[u8]) -> Result<f64, usize>;
+
+/// 格式对应的扫描函数
+fn scanner(format: CoordFormat) -> SingleScanner {
+    match format {
+        CoordFormat::DegreeMinute => scan_dm,
+        CoordFormat::DegreeMinuteSecondDecimal => scan_dms_decimal,
+        CoordFormat::Arinc424 => scan_dms_centi,
+        CoordFormat::DegreeMinuteSecond => scan_dms,
+        CoordFormat::EuroScope => scan_es,
+        CoordFormat::Pmdg => scan_pmdg,
+        CoordFormat::Decimal => scan_decimal,
+        CoordFormat::Icao => scan_icao,
+        CoordFormat::Symbolic => scan_symbolic,
+        CoordFormat::HemisphereDecimal => scan_hemisphere_decimal,
+        // 网格坐标同时包含经纬度，由`Coordinate::parse_with`单独处理
+        CoordFormat::Utm | CoordFormat::Mgrs => |_| Err(0),
+    }
+}
+
+/// 分的换算比例
+const MINUTE: f64 = 1.0 / 60.0;
+/// 秒的换算比例
+const SECOND: f64 = 1.0 / 3600.0;
+/// 百分之一秒的换算比例
+const CENTISECOND: f64 = 1.0 / 360000.0;
+
+/// 按字节读取输入的游标，出错时返回当前位置
+struct Cursor<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(s: &'a [u8]) -> Self {
+        Cursor { s, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    /// 读取半球标识`[NSEW]`，返回对应的符号
+    fn hemisphere(&mut self) -> Result<f64, usize> {
+        let sign = match self.peek() {
+            Some(b'N' | b'E') => 1.0,
+            Some(b'S' | b'W') => -1.0,
+            _ => return Err(self.pos),
+        };
+        self.pos += 1;
+        Ok(sign)
+    }
+
+    /// 跳过空格
+    fn spaces(&mut self) {
+        while self.peek() == Some(b' ') {
+            self.pos += 1;
+        }
+    }
+
+    /// 读取指定的字符串
+    fn bytes(&mut self, t: &str) -> Result<(), usize> {
+        if self.s[self.pos..].starts_with(t.as_bytes()) {
+            self.pos += t.len();
+            Ok(())
+        } else {
+            Err(self.pos)
+        }
+    }
+
+    /// 读取指定的字符
+    fn byte(&mut self, c: u8) -> Result<(), usize> {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.pos)
+        }
+    }
+
+    /// 读取连续的数字，返回数字个数
+    fn count_digits(&self) -> usize {
+        self.s[self.pos..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count()
+    }
+
+    /// 读取`min`到`max`个数字
+    fn digits(&mut self, min: usize, max: usize) -> Result<Range<usize>, usize> {
+        let start = self.pos;
+        let n = self.count_digits();
+        if n > max {
+            return Err(start + max);
+        }
+        if n < min {
+            return Err(start + n);
+        }
+        self.pos += n;
+        Ok(start..self.pos)
+    }
+
+    /// 读取以度开头的连续数字，相当于`[01]?\d{len}`
+    fn degree_digits(&mut self, len: usize) -> Result<Range<usize>, usize> {
+        let max = match self.peek() {
+            Some(b'0' | b'1') => len + 1,
+            _ => len,
+        };
+        self.digits(len, max)
+    }
+
+    /// 读取小数点及其后的任意个数字
+    fn fraction(&mut self) -> Result<(), usize> {
+        self.byte(b'.')?;
+        self.pos += self.count_digits();
+        Ok(())
+    }
+
+    /// 确认已经读到结尾
+    fn end(&self) -> Result<(), usize> {
+        if self.pos == self.s.len() {
+            Ok(())
+        } else {
+            Err(self.pos)
+        }
+    }
+
+    /// 指定范围的数值
+    fn num(&self, range: Range<usize>) -> f64 {
+        std::str::from_utf8(&self.s[range])
+            .ok()
+            .and_then(|s| s.parse::<f64>().ok())
+            .unwrap_or(0.0)
+    }
+}
+
+/// `[NSEW]DD(D)MM.M`
+fn scan_dm(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    let sign = c.hemisphere()?;
+    let run = c.degree_digits(4)?;
+    c.fraction()?;
+    c.end()?;
+    let m = run.end - 2;
+    Ok((c.num(run.start..m) + c.num(m..c.pos) * MINUTE) * sign)
+}
+
+/// `[NSEW]DD(D)MMSS.S`
+fn scan_dms_decimal(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    let sign = c.hemisphere()?;
+    let run = c.degree_digits(6)?;
+    c.fraction()?;
+    c.end()?;
+    let (m, sec) = (run.end - 4, run.end - 2);
+    Ok((c.num(run.start..m) + c.num(m..sec) * MINUTE + c.num(sec..c.pos) * SECOND) * sign)
+}
+
+/// `[NSEW]DD(D)MMSSCC`（ARINC 424）
+fn scan_dms_centi(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    let sign = c.hemisphere()?;
+    let run = c.degree_digits(8)?;
+    c.end()?;
+    let (m, sec, cs) = (run.end - 6, run.end - 4, run.end - 2);
+    Ok((c.num(run.start..m)
+        + c.num(m..sec) * MINUTE
+        + c.num(sec..cs) * SECOND
+        + c.num(cs..run.end) * CENTISECOND)
+        * sign)
+}
+
+/// `[NSEW]DD(D)MMSS`
+fn scan_dms(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    let sign = c.hemisphere()?;
+    let run = c.degree_digits(6)?;
+    c.end()?;
+    let (m, sec) = (run.end - 4, run.end - 2);
+    Ok((c.num(run.start..m) + c.num(m..sec) * MINUTE + c.num(sec..run.end) * SECOND) * sign)
+}
+
+/// `[NSEW]DDD.MM.SS.SSS`（EuroScope）
+fn scan_es(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    let sign = c.hemisphere()?;
+    let d = c.digits(3, 3)?;
+    c.byte(b'.')?;
+    let m = c.digits(2, 2)?;
+    c.byte(b'.')?;
+    let sec = c.pos;
+    c.digits(2, 2)?;
+    c.byte(b'.')?;
+    c.digits(3, 3)?;
+    c.end()?;
+    Ok((c.num(d) + c.num(m) * MINUTE + c.num(sec..c.pos) * SECOND) * sign)
+}
+
+/// `[NSEW] DD(D) MM.M`（PMDG）
+fn scan_pmdg(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    let sign = c.hemisphere()?;
+    c.byte(b' ')?;
+    let d = c.degree_digits(2)?;
+    c.byte(b' ')?;
+    let m = c.pos;
+    c.digits(2, 2)?;
+    c.fraction()?;
+    c.end()?;
+    Ok((c.num(d) + c.num(m..c.pos) * MINUTE) * sign)
+}
+
+/// `[+-]DD(D).D`
+fn scan_decimal(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    let sign = match c.peek() {
+        Some(b'-') => -1.0,
+        _ => 1.0,
+    };
+    if matches!(c.peek(), Some(b'+' | b'-')) {
+        c.pos += 1;
+    }
+    let start = c.pos;
+    c.digits(2, 3)?;
+    c.fraction()?;
+    c.end()?;
+    Ok(c.num(start..c.pos) * sign)
+}
+
+/// `[NS]D(D).D`或`[EW]D(DD).D`，纬度的整数部分最多2位
+fn scan_hemisphere_decimal(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    let max = match c.peek() {
+        Some(b'N' | b'S') => 2,
+        _ => 3,
+    };
+    let sign = c.hemisphere()?;
+    let start = c.pos;
+    c.digits(1, max)?;
+    c.fraction()?;
+    c.end()?;
+    Ok(c.num(start..c.pos) * sign)
+}
+
+/// `DD(D)[MM[SS]][.S][NSEW]`（ICAO Doc 4444，半球标识在后）
+fn scan_icao(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    let n = c.count_digits();
+    let lead = matches!(c.peek(), Some(b'0' | b'1'));
+    // 不含度的百位时的数字个数
+    let width = match n {
+        2 | 4 | 6 => n,
+        3 | 5 | 7 if lead => n - 1,
+        _ => return Err(n.min(if lead { 7 } else { 6 })),
+    };
+    c.pos = n;
+    if c.peek() == Some(b'.') {
+        c.fraction()?;
+    }
+    let end = c.pos;
+    let sign = c.hemisphere()?;
+    c.end()?;
+
+    // 度、分、秒的结束位置，小数部分属于最后一项
+    let (d, m) = match width {
+        2 => (end, end),
+        4 => (n - 2, end),
+        _ => (n - 4, n - 2),
+    };
+    Ok((c.num(0..d) + c.num(d..m) * MINUTE + c.num(m..end) * SECOND) * sign)
+}
+
+/// `[NSEW]DD°[MM'[SS"]][NSEW]`，使用度分秒符号，各部分之间可以有空格，最后一项可以带小数
+fn scan_symbolic(s: &[u8]) -> Result<f64, usize> {
+    let mut c = Cursor::new(s);
+    c.spaces();
+    let mut sign = match c.peek() {
+        Some(b'-') => -1.0,
+        _ => 1.0,
+    };
+    let mut hemisphere = false;
+    if matches!(c.peek(), Some(b'+' | b'-')) {
+        c.pos += 1;
+    } else if let Ok(value) = c.hemisphere() {
+        sign = value;
+        hemisphere = true;
+        c.spaces();
+    }
+
+    let mut sum = 0.0;
+    for (mark, ratio) in [("°", 1.0), ("'", MINUTE), ("\"", SECOND)] {
+        if mark != "°" && !c.peek().is_some_and(|b| b.is_ascii_digit()) {
+            break;
+        }
+        let start = c.pos;
+        c.digits(1, usize::MAX)?;
+        let decimal = c.peek() == Some(b'.');
+        if decimal {
+            c.fraction()?;
+        }
+        let end = c.pos;
+        match (mark, c.bytes(mark)) {
+            (_, Ok(())) => {}
+            // 秒也可以写成两个单引号
+            ("\"", Err(_)) => c.bytes("''")?,
+            (_, Err(pos)) => return Err(pos),
+        }
+        sum += c.num(start..end) * ratio;
+        c.spaces();
+        if decimal {
+            break;
+        }
+    }
+
+    if !hemisphere {
+        if let Ok(value) = c.hemisphere() {
+            sign *= value;
+            c.spaces();
+        }
+    }
+    // 容许以逗号结尾，例如“北纬40°30′，东经116°30′”
+    if c.peek() == Some(b',') {
+        c.pos += 1;
+        c.spaces();
+    }
+    c.end()?;
+    Ok(sum * sign)
+}
+
+/// 将单个数值的字符串变成f64类型的数值
+///
+/// # 参数
+///
+/// - `s` - 单个数值的字符串
+/// - `format` - 指定的格式，为`None`时按顺序尝试所有格式
+///
+/// # 返回值
+///
+/// 数值，无法处理时返回最接近的格式（能匹配的前缀最长者），没有任何格式能匹配开头时为`None`
+fn parse_single(s: &str, format: Option<CoordFormat>) -> Result<f64, Option<CoordFormat>> {
+    let formats = match format {
+        Some(ref format) => std::slice::from_ref(format),
+        None => &CoordFormat::ALL[..],
+    };
+    let mut closest: Option<(usize, CoordFormat)> = None;
+    for &format in formats {
+        match scanner(format)(s.as_bytes()) {
+            Ok(value) => return Ok(value),
+            Err(len) => {
+                if len > closest.map_or(0, |(best, _)| best) {
+                    closest = Some((len, format));
+                }
+            }
+        }
+    }
+    Err(closest.map(|(_, format)| format))
+}
+
+/// 查找与输入最接近的格式
+fn closest_format(s: &str, format: Option<CoordFormat>) -> Option<CoordFormat> {
+    parse_single(s, format).err().flatten()
+}
+
+/// 将全角字符、中文半球标识和各种度分秒符号折算为扫描器使用的字符，`None`表示忽略该字符
+fn fold_char(c: char) -> Option<char> {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0),
+        '\u{3000}' => Some(' '),
+        '北' => Some('N'),
+        '南' => Some('S'),
+        '东' | '東' => Some('E'),
+        '西' => Some('W'),
+        '纬' | '緯' | '经' | '經' => None,
+        'º' | '˚' | '度' => Some('°'),
+        '′' | '‘' | '’' | '分' => Some('\''),
+        '″' | '“' | '”' | '秒' => Some('"'),
+        _ => Some(c),
+    }
+}
+
+/// 经过折算的输入
+struct Folded {
+    /// 折算后的文本
+    text: String,
+    /// 折算后每个字节对应的原始字节位置（多一项表示结尾）
+    offsets: Vec<usize>,
+}
+
+impl Folded {
+    fn new(s: &str) -> Self {
+        let mut text = String::with_capacity(s.len());
+        let mut offsets = Vec::with_capacity(s.len() + 1);
+        for (i, c) in s.char_indices() {
+            if let Some(c) = fold_char(c) {
+                text.push(c);
+                offsets.resize(text.len(), i);
+            }
+        }
+        offsets.push(s.len());
+        Folded { text, offsets }
+    }
+
+    /// 折算后的字节范围对应的原始字节范围
+    fn original(&self, span: Range<usize>) -> Range<usize> {
+        self.offsets[span.start]..self.offsets[span.end]
+    }
+}
+
+/// 扫描得到的两个数值及其在输入中的字节范围，按在输入中出现的顺序排列
+pub(crate) struct Scanned {
+    pub first: f64,
+    pub first_span: Range<usize>,
+    pub second: f64,
+    pub second_span: Range<usize>,
+}
+
+/// 扫描失败时的错误类别、出错部分的字节范围和最接近的格式。
+/// 第一个数值出错时为`LatParseError`，第二个数值出错时为`LonParseError`
+pub(crate) type ScanError = (CoordParseErrorKind, Range<usize>, Option<CoordFormat>);
+
+/// 扫描包含两个数值的字符串，非ASCII输入先经过折算，返回的范围对应原始输入
+///
+/// # 参数
+///
+/// - `s` - 坐标字符串
+/// - `format` - 指定的格式，为`None`时两个数值可以是任意格式
+pub(crate) fn scan(s: &str, format: Option<CoordFormat>) -> Result<Scanned, ScanError> {
+    if s.is_ascii() {
+        return scan_folded(s, format);
+    }
+
+    let folded = Folded::new(s);
+    match scan_folded(&folded.text, format) {
+        Ok(scanned) => Ok(Scanned {
+            first_span: folded.original(scanned.first_span),
+            second_span: folded.original(scanned.second_span),
+            ..scanned
+        }),
+        Err((kind, span, closest)) => Err((kind, folded.original(span), closest)),
+    }
+}
+
+fn scan_folded(s: &str, format: Option<CoordFormat>) -> Result<Scanned, ScanError> {
+    let (first_span, second_span) = split(s).ok_or_else(|| {
+        (
+            CoordParseErrorKind::NoMatchingFormat,
+            0..s.len(),
+            closest_format(s, format),
+        )
+    })?;
+
+    let first = parse_single(&s[first_span.clone()], format).map_err(|closest| {
+        (
+            CoordParseErrorKind::LatParseError,
+            first_span.clone(),
+            closest,
+        )
+    })?;
+
+    let second = parse_single(&s[second_span.clone()], format).map_err(|closest| {
+        (
+            CoordParseErrorKind::LonParseError,
+            second_span.clone(),
+            closest,
+        )
+    })?;
+
+    Ok(Scanned {
+        first,
+        first_span,
+        second,
+        second_span,
+    })
+}
+
+/// 数值中的半球标识（包括全角和中文标识）
+pub(crate) fn hemisphere_hint(s: &str) -> Option<Hemisphere> {
+    s.chars().find_map(|c| match fold_char(c) {
+        Some('N') => Some(Hemisphere::N),
+        Some('S') => Some(Hemisphere::S),
+        Some('E') => Some(Hemisphere::E),
+        Some('W') => Some(Hemisphere::W),
+        _ => None,
+    })
+}
+
+/// 将包含经纬度的字符串拆分为纬度和经度两部分
+///
+/// 依次尝试以下规则：
+/// - 以`N`/`S`开头，在第一个`E`/`W`（可带一个前导空格）处拆分
+/// - 在第一个`,`处拆分
+/// - 恰好包含一个空格时在空格处拆分
+/// - 以数字开头，在第一个`N`/`S`之后（可带一个空格）拆分
+/// - 以`E`/`W`开头，在第一个`N`/`S`（可带一个前导空格）处拆分
+///
+/// # 返回值
+///
+/// 纬度和经度部分的字节范围，没有匹配的规则时为`None`
+fn split(s: &str) -> Option<(Range<usize>, Range<usize>)> {
+    let b = s.as_bytes();
+    let single_line = !b.contains(&b'\n');
+
+    // 以`first`中的字符开头时，在第一个`second`中的字符（可带一个前导空格）处拆分
+    let split_at_hemisphere = |first: &[u8], second: &[u8]| {
+        if !b.first().is_some_and(|c| first.contains(c)) {
+            return None;
+        }
+        let is_second = |c: Option<&u8>| c.is_some_and(|c| second.contains(c));
+        (1..b.len()).find_map(|k| {
+            if b[k] == b' ' && is_second(b.get(k + 1)) {
+                Some((0..k, k + 1..b.len()))
+            } else if is_second(b.get(k)) {
+                Some((0..k, k..b.len()))
+            } else {
+                None
+            }
+        })
+    };
+
+    if single_line {
+        if let Some(spans) = split_at_hemisphere(b"NS", b"EW") {
+            return Some(spans);
+        }
+    }
+
+    if single_line {
+        if let Some(k) = b.iter().position(|&c| c == b',') {
+            return Some((0..k, k + 1..b.len()));
+        }
+    }
+
+    let mut spaces = b.iter().enumerate().filter(|(_, &c)| c == b' ');
+    if let (Some((k, _)), None) = (spaces.next(), spaces.next()) {
+        return Some((0..k, k + 1..b.len()));
+    }
+
+    if single_line && b.first().is_some_and(u8::is_ascii_digit) {
+        if let Some(k) = b.iter().position(|&c| c == b'N' || c == b'S') {
+            let lon = if b.get(k + 1) == Some(&b' ') {
+                k + 2
+            } else {
+                k + 1
+            };
+            return Some((0..k + 1, lon..b.len()));
+        }
+    }
+
+    if single_line {
+        return split_at_hemisphere(b"EW", b"NS");
+    }
+
+    None
+}
@@ -0,0 +1,406 @@
+//! UTM/UPS投影
+
+use super::{normalize_lon, CoordRangeError, Coordinate};
+use crate::Ellipsoid;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// UTM/UPS使用的WGS-84椭球
+const WGS84: Ellipsoid = Ellipsoid::WGS84;
+/// UTM中央经线比例因子
+const UTM_K0: f64 = 0.9996;
+/// UPS极点比例因子
+const UPS_K0: f64 = 0.994;
+/// UTM东向偏移
+const UTM_FALSE_EASTING: f64 = 500_000.0;
+/// UTM南半球北向偏移
+pub(crate) const UTM_FALSE_NORTHING: f64 = 10_000_000.0;
+/// UPS东向和北向偏移
+const UPS_FALSE_ORIGIN: f64 = 2_000_000.0;
+
+/// UTM/UPS转换时可能出现的错误
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum GridError {
+    /// 坐标超出范围
+    OutOfRange(CoordRangeError),
+    /// 带号不在[0, 60]范围内（0表示UPS）
+    InvalidZone(u8),
+    /// 东向或北向坐标超出所在带的范围
+    OutOfZone,
+    /// 无法处理的UTM或MGRS字符串
+    InvalidFormat,
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::OutOfRange(e) => write!(f, "{}", e),
+            GridError::InvalidZone(zone) => write!(f, "zone {} out of range [0, 60]", zone),
+            GridError::OutOfZone => write!(f, "easting or northing outside the zone"),
+            GridError::InvalidFormat => write!(f, "invalid grid reference"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// 保角纬度的正切值
+fn taupf(tau: f64, e: f64) -> f64 {
+    let tau1 = 1f64.hypot(tau);
+    let sig = (e * (e * tau / tau1).atanh()).sinh();
+    1f64.hypot(sig) * tau - sig * tau1
+}
+
+/// `taupf`的反函数，牛顿迭代，次数有上限
+fn tauf(taup: f64, e: f64) -> f64 {
+    let e2m = 1.0 - e * e;
+    let tol = f64::EPSILON.sqrt() / 10.0 * taup.abs().max(1.0);
+    let mut tau = if taup.abs() > 70.0 {
+        taup * (e * e.atanh()).exp()
+    } else {
+        taup / e2m
+    };
+    // 无穷大、NaN和接近极点的值不需要迭代
+    if tau.is_nan() || tau.abs() >= 2.0 / f64::EPSILON.sqrt() {
+        return tau;
+    }
+    for _ in 0..5 {
+        let taupa = taupf(tau, e);
+        let dtau =
+            (taup - taupa) * (1.0 + e2m * tau * tau) / (e2m * 1f64.hypot(tau) * 1f64.hypot(taupa));
+        tau += dtau;
+        if dtau.is_nan() || dtau.abs() < tol {
+            break;
+        }
+    }
+    tau
+}
+
+/// 横轴墨卡托投影，使用6阶Krüger级数（Karney 2011），在中央经线两侧数千千米内误差为纳米级
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct TransverseMercator {
+    /// 偏心率
+    e: f64,
+    /// 比例因子与子午线周长对应的半径之积
+    k0_a: f64,
+    alpha: [f64; 6],
+    beta: [f64; 6],
+}
+
+impl TransverseMercator {
+    /// 创建投影
+    ///
+    /// # 参数
+    ///
+    /// - `a` - 椭球长半轴，单位为米
+    /// - `f` - 椭球扁率
+    /// - `k0` - 中央经线比例因子
+    pub(crate) fn new(a: f64, f: f64, k0: f64) -> Self {
+        let n = f / (2.0 - f);
+        let (n2, n3) = (n * n, n * n * n);
+        let (n4, n5, n6) = (n3 * n, n3 * n2, n3 * n3);
+        let rectifying = a / (1.0 + n) * (1.0 + n2 / 4.0 + n4 / 64.0 + n6 / 256.0);
+        TransverseMercator {
+            e: (f * (2.0 - f)).sqrt(),
+            k0_a: k0 * rectifying,
+            alpha: [
+                n / 2.0 - 2.0 / 3.0 * n2 + 5.0 / 16.0 * n3 + 41.0 / 180.0 * n4 - 127.0 / 288.0 * n5
+                    + 7891.0 / 37800.0 * n6,
+                13.0 / 48.0 * n2 - 3.0 / 5.0 * n3 + 557.0 / 1440.0 * n4 + 281.0 / 630.0 * n5
+                    - 1983433.0 / 1935360.0 * n6,
+                61.0 / 240.0 * n3 - 103.0 / 140.0 * n4
+                    + 15061.0 / 26880.0 * n5
+                    + 167603.0 / 181440.0 * n6,
+                49561.0 / 161280.0 * n4 - 179.0 / 168.0 * n5 + 6601661.0 / 7257600.0 * n6,
+                34729.0 / 80640.0 * n5 - 3418889.0 / 1995840.0 * n6,
+                212378941.0 / 319334400.0 * n6,
+            ],
+            beta: [
+                n / 2.0 - 2.0 / 3.0 * n2 + 37.0 / 96.0 * n3 - 1.0 / 360.0 * n4 - 81.0 / 512.0 * n5
+                    + 96199.0 / 604800.0 * n6,
+                1.0 / 48.0 * n2 + 1.0 / 15.0 * n3 - 437.0 / 1440.0 * n4 + 46.0 / 105.0 * n5
+                    - 1118711.0 / 3870720.0 * n6,
+                17.0 / 480.0 * n3 - 37.0 / 840.0 * n4 - 209.0 / 4480.0 * n5 + 5569.0 / 90720.0 * n6,
+                4397.0 / 161280.0 * n4 - 11.0 / 504.0 * n5 - 830251.0 / 7257600.0 * n6,
+                4583.0 / 161280.0 * n5 - 108847.0 / 3991680.0 * n6,
+                20648693.0 / 638668800.0 * n6,
+            ],
+        }
+    }
+
+    /// 正算
+    ///
+    /// # 参数
+    ///
+    /// - `lat` - 纬度，范围为(-90, 90)
+    /// - `dlon` - 与中央经线的经度差
+    ///
+    /// # 返回值
+    ///
+    /// 相对中央经线与赤道交点的东向和北向坐标，单位为米
+    pub(crate) fn forward(&self, lat: f64, dlon: f64) -> (f64, f64) {
+        let taup = taupf(lat.to_radians().tan(), self.e);
+        let (sin_l, cos_l) = dlon.to_radians().sin_cos();
+        let xip = taup.atan2(cos_l);
+        let etap = (sin_l / taup.hypot(cos_l)).asinh();
+
+        let (mut xi, mut eta) = (xip, etap);
+        for (j, alpha) in self.alpha.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xi += alpha * (k * xip).sin() * (k * etap).cosh();
+            eta += alpha * (k * xip).cos() * (k * etap).sinh();
+        }
+        (self.k0_a * eta, self.k0_a * xi)
+    }
+
+    /// 反算
+    ///
+    /// # 参数
+    ///
+    /// - `x` - 相对中央经线的东向坐标，单位为米
+    /// - `y` - 相对赤道的北向坐标，单位为米
+    ///
+    /// # 返回值
+    ///
+    /// 纬度和与中央经线的经度差
+    pub(crate) fn reverse(&self, x: f64, y: f64) -> (f64, f64) {
+        let (xi, eta) = (y / self.k0_a, x / self.k0_a);
+        let (mut xip, mut etap) = (xi, eta);
+        for (j, beta) in self.beta.iter().enumerate() {
+            let k = 2.0 * (j + 1) as f64;
+            xip -= beta * (k * xi).sin() * (k * eta).cosh();
+            etap -= beta * (k * xi).cos() * (k * eta).sinh();
+        }
+
+        let sinh_etap = etap.sinh();
+        let (sin_xip, cos_xip) = xip.sin_cos();
+        let r = sinh_etap.hypot(cos_xip);
+        if r == 0.0 {
+            return (90f64.copysign(xip), 0.0);
+        }
+        let tau = tauf(sin_xip / r, self.e);
+        (
+            tau.atan().to_degrees(),
+            sinh_etap.atan2(cos_xip).to_degrees(),
+        )
+    }
+}
+
+/// UTM使用的横轴墨卡托投影
+pub(crate) fn utm_projection() -> TransverseMercator {
+    TransverseMercator::new(WGS84.a, WGS84.f, UTM_K0)
+}
+
+/// WGS-84椭球偏心率
+fn eccentricity() -> f64 {
+    WGS84.e2().sqrt()
+}
+
+/// 极球面投影（UPS）的投影半径系数`2 * k0 * a / c`
+fn ups_scale() -> f64 {
+    let e = eccentricity();
+    let c = (1.0 - e * e).sqrt() * (e * e.atanh()).exp();
+    2.0 * UPS_K0 * WGS84.a / c
+}
+
+/// UTM/UPS坐标
+///
+/// UTM使用6度分带（包括挪威和斯瓦尔巴群岛的例外），适用于[-80, 84)纬度范围，
+/// 范围以外使用UPS，此时带号为0
+#[derive(Debug, PartialEq, Serialize, Deserialize, Copy, Clone)]
+pub struct Utm {
+    /// 带号，1~60，UPS为0
+    pub zone: u8,
+    /// 是否在北半球
+    pub north: bool,
+    /// 东向坐标，单位为米
+    pub easting: f64,
+    /// 北向坐标，单位为米
+    pub northing: f64,
+}
+
+/// 坐标所在的UTM带号，需要使用UPS时为0
+fn zone_of(lat: f64, lon: f64) -> u8 {
+    if !(-80.0..84.0).contains(&lat) {
+        return 0;
+    }
+    if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lon) {
+        return 32;
+    }
+    if (72.0..84.0).contains(&lat) && (0.0..42.0).contains(&lon) {
+        return match lon {
+            lon if lon < 9.0 => 31,
+            lon if lon < 21.0 => 33,
+            lon if lon < 33.0 => 35,
+            _ => 37,
+        };
+    }
+    (((lon + 180.0) / 6.0).floor() as u8 + 1).min(60)
+}
+
+/// UTM带的中央经线
+fn central_meridian(zone: u8) -> f64 {
+    zone as f64 * 6.0 - 183.0
+}
+
+impl Utm {
+    /// 将坐标转换为UTM/UPS坐标，自动选择UTM带或UPS
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - 坐标，经度可以超出[-180, 180]范围
+    ///
+    /// # 返回值
+    ///
+    /// UTM/UPS坐标，纬度超出[-90, 90]或经度不是有限值时返回错误
+    pub fn from_coordinate(coord: &Coordinate) -> Result<Self, GridError> {
+        let Coordinate { lat, lon } = coord.normalize().map_err(GridError::OutOfRange)?;
+        let north = lat >= 0.0;
+        let zone = zone_of(lat, lon);
+
+        let (easting, northing) = if zone == 0 {
+            let lat = if north { lat } else { -lat };
+            let rho = if lat == 90.0 {
+                0.0
+            } else {
+                let taup = taupf(lat.to_radians().tan(), eccentricity());
+                ups_scale() / (1f64.hypot(taup) + taup)
+            };
+            let (sin_l, cos_l) = lon.to_radians().sin_cos();
+            let y = if north { -rho * cos_l } else { rho * cos_l };
+            (UPS_FALSE_ORIGIN + rho * sin_l, UPS_FALSE_ORIGIN + y)
+        } else {
+            let dlon = normalize_lon(lon - central_meridian(zone));
+            let (x, y) = utm_projection().forward(lat, dlon);
+            let y = if north { y } else { y + UTM_FALSE_NORTHING };
+            (x + UTM_FALSE_EASTING, y)
+        };
+
+        Ok(Utm {
+            zone,
+            north,
+            easting,
+            northing,
+        })
+    }
+
+    /// 转换为坐标
+    ///
+    /// # 返回值
+    ///
+    /// 坐标，经度在[-180, 180)范围内。带号无效或东向、北向坐标明显超出所在带时返回错误
+    pub fn to_coordinate(&self) -> Result<Coordinate, GridError> {
+        if self.zone > 60 {
+            return Err(GridError::InvalidZone(self.zone));
+        }
+        let (easting, northing) = (self.easting, self.northing);
+
+        // 与GeographicLib相同的有效范围，以免把其他格式的数字误当作网格坐标
+        let (eastings, northings) = match (self.zone, self.north) {
+            (0, false) => (800_000.0..=3_200_000.0, 800_000.0..=3_200_000.0),
+            (0, true) => (1_300_000.0..=2_700_000.0, 1_300_000.0..=2_700_000.0),
+            (_, false) => (100_000.0..=900_000.0, 1_000_000.0..=UTM_FALSE_NORTHING),
+            (_, true) => (100_000.0..=900_000.0, 0.0..=9_500_000.0),
+        };
+        if !eastings.contains(&easting) || !northings.contains(&northing) {
+            return Err(GridError::OutOfZone);
+        }
+
+        if self.zone == 0 {
+            let (x, y) = (easting - UPS_FALSE_ORIGIN, northing - UPS_FALSE_ORIGIN);
+            let rho = x.hypot(y);
+            let (lat, lon) = if rho == 0.0 {
+                (90.0, 0.0)
+            } else {
+                let t = rho / ups_scale();
+                let tau = tauf((1.0 / t - t) / 2.0, eccentricity());
+                let y = if self.north { -y } else { y };
+                (tau.atan().to_degrees(), x.atan2(y).to_degrees())
+            };
+            let lat = if self.north { lat } else { -lat };
+            return Ok(Coordinate {
+                lat,
+                lon: normalize_lon(lon),
+            });
+        }
+
+        let y = if self.north {
+            northing
+        } else {
+            northing - UTM_FALSE_NORTHING
+        };
+        let (lat, dlon) = utm_projection().reverse(easting - UTM_FALSE_EASTING, y);
+        Ok(Coordinate {
+            lat,
+            lon: normalize_lon(central_meridian(self.zone) + dlon),
+        })
+    }
+}
+
+impl Display for Utm {
+    /// 以`50N 448251 4419478`格式输出，UPS省略带号，例如`N 2000000 2000000`。
+    /// 可以用`{:.2}`指定米的小数位数
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        if self.zone != 0 {
+            write!(f, "{}", self.zone)?;
+        }
+        write!(
+            f,
+            "{} {:.*} {:.*}",
+            if self.north { 'N' } else { 'S' },
+            precision,
+            self.easting,
+            precision,
+            self.northing
+        )
+    }
+}
+
+impl FromStr for Utm {
+    type Err = GridError;
+
+    /// 处理`50N 448251 4419478`格式的字符串，半球标识不区分大小写，UPS省略带号
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        let [zone, easting, northing] = parts[..] else {
+            return Err(GridError::InvalidFormat);
+        };
+
+        let (zone, north) = match zone.char_indices().last() {
+            Some((i, 'N' | 'n')) => (&zone[..i], true),
+            Some((i, 'S' | 's')) => (&zone[..i], false),
+            _ => return Err(GridError::InvalidFormat),
+        };
+        let zone = match zone {
+            "" => 0,
+            zone if zone.len() <= 2 && zone.bytes().all(|c| c.is_ascii_digit()) => {
+                match zone.parse() {
+                    Ok(zone @ 1..=60) => zone,
+                    Ok(zone) => return Err(GridError::InvalidZone(zone)),
+                    Err(_) => return Err(GridError::InvalidFormat),
+                }
+            }
+            _ => return Err(GridError::InvalidFormat),
+        };
+
+        let metres = |s: &str| match s.parse::<f64>() {
+            Ok(value) if s.bytes().all(|c| c.is_ascii_digit() || c == b'.') => Ok(value),
+            _ => Err(GridError::InvalidFormat),
+        };
+        Ok(Utm {
+            zone,
+            north,
+            easting: metres(easting)?,
+            northing: metres(northing)?,
+        })
+    }
+}
+
+impl Coordinate {
+    /// 转换为UTM/UPS坐标，参见`Utm::from_coordinate`
+    pub fn to_utm(&self) -> Result<Utm, GridError> {
+        Utm::from_coordinate(self)
+    }
+}
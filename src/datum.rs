@@ -0,0 +1,12 @@
+//! 坐标系转换
+
+mod gauss_kruger;
+mod gcj02;
+mod helmert;
+
+pub use gauss_kruger::{GaussKruger, ZoneWidth};
+pub use gcj02::{
+    bd09_to_gcj02, bd09_to_wgs84, gcj02_to_bd09, gcj02_to_wgs84, is_in_china, transform,
+    transform_slice, wgs84_to_bd09, wgs84_to_gcj02, MapSystem, INVERSE_TOLERANCE,
+};
+pub use helmert::{Datum, DatumError, Helmert};
@@ -0,0 +1,171 @@
+//! 高斯-克吕格投影
+
+use crate::coordinate::{normalize_lon, GridError, TransverseMercator};
+use crate::{Coordinate, Ellipsoid};
+use serde::{Deserialize, Serialize};
+
+/// 东坐标的偏移
+const FALSE_EASTING: f64 = 500_000.0;
+
+/// 分带方式
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy, Clone)]
+pub enum ZoneWidth {
+    /// 3度带，第n带的中央经线为3n度，带号1~120
+    Three,
+    /// 6度带，第n带的中央经线为6n-3度，带号1~60
+    Six,
+}
+
+impl ZoneWidth {
+    /// 最大带号
+    fn max_zone(&self) -> u8 {
+        match self {
+            ZoneWidth::Three => 120,
+            ZoneWidth::Six => 60,
+        }
+    }
+
+    /// 经度所在的带号
+    pub fn zone_of(&self, lon: f64) -> u8 {
+        // 带号从0°经线开始向东编号
+        let lon = normalize_lon(lon).rem_euclid(360.0);
+        match self {
+            // 第120带的中央经线为360°，包括0°经线两侧
+            ZoneWidth::Three => match ((lon + 1.5) / 3.0).floor() as u8 {
+                0 => 120,
+                zone => zone.min(120),
+            },
+            ZoneWidth::Six => ((lon / 6.0).floor() as u8 + 1).min(60),
+        }
+    }
+
+    /// 带的中央经线，范围为(-180, 180]
+    pub fn central_meridian(&self, zone: u8) -> f64 {
+        let lon = match self {
+            ZoneWidth::Three => 3.0 * zone as f64,
+            ZoneWidth::Six => 6.0 * zone as f64 - 3.0,
+        };
+        if lon > 180.0 {
+            lon - 360.0
+        } else {
+            lon
+        }
+    }
+}
+
+/// 高斯-克吕格平面坐标，中央经线比例因子为1，东坐标加500千米
+#[derive(Debug, PartialEq, Serialize, Deserialize, Copy, Clone)]
+pub struct GaussKruger {
+    /// 分带方式
+    pub zone_width: ZoneWidth,
+    /// 带号
+    pub zone: u8,
+    /// 北坐标（X），单位为米
+    pub northing: f64,
+    /// 东坐标（Y），不含带号，单位为米
+    pub easting: f64,
+}
+
+impl GaussKruger {
+    /// 投影到坐标所在的带
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - 坐标，应与`ellipsoid`属于同一基准
+    /// - `ellipsoid` - 基准使用的椭球，例如CGCS2000使用`Ellipsoid::CGCS2000`
+    /// - `zone_width` - 分带方式
+    pub fn from_coordinate(
+        coord: &Coordinate,
+        ellipsoid: &Ellipsoid,
+        zone_width: ZoneWidth,
+    ) -> Result<Self, GridError> {
+        let zone = zone_width.zone_of(coord.lon);
+        GaussKruger::from_coordinate_in_zone(coord, ellipsoid, zone_width, zone)
+    }
+
+    /// 投影到指定的带，坐标可以在相邻带内
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - 坐标，应与`ellipsoid`属于同一基准
+    /// - `ellipsoid` - 基准使用的椭球
+    /// - `zone_width` - 分带方式
+    /// - `zone` - 带号
+    ///
+    /// # 返回值
+    ///
+    /// 平面坐标，带号无效或坐标超出范围时返回错误
+    pub fn from_coordinate_in_zone(
+        coord: &Coordinate,
+        ellipsoid: &Ellipsoid,
+        zone_width: ZoneWidth,
+        zone: u8,
+    ) -> Result<Self, GridError> {
+        if !(1..=zone_width.max_zone()).contains(&zone) {
+            return Err(GridError::InvalidZone(zone));
+        }
+        let coord = coord.normalize().map_err(GridError::OutOfRange)?;
+        if coord.lat.abs() == 90.0 {
+            return Err(GridError::OutOfZone);
+        }
+        let dlon = normalize_lon(coord.lon - zone_width.central_meridian(zone));
+        let (x, y) =
+            TransverseMercator::new(ellipsoid.a, ellipsoid.f, 1.0).forward(coord.lat, dlon);
+        Ok(GaussKruger {
+            zone_width,
+            zone,
+            northing: y,
+            easting: x + FALSE_EASTING,
+        })
+    }
+
+    /// 由带号在前的东坐标创建，例如3度带第39带的`39500000`
+    ///
+    /// # 参数
+    ///
+    /// - `zone_width` - 分带方式
+    /// - `northing` - 北坐标，单位为米
+    /// - `easting` - 带号在前的东坐标，单位为米
+    pub fn from_prefixed(
+        zone_width: ZoneWidth,
+        northing: f64,
+        easting: f64,
+    ) -> Result<Self, GridError> {
+        let zone = (easting / 1_000_000.0).floor();
+        if !(1.0..=zone_width.max_zone() as f64).contains(&zone) {
+            return Err(GridError::InvalidZone(zone.clamp(0.0, 255.0) as u8));
+        }
+        Ok(GaussKruger {
+            zone_width,
+            zone: zone as u8,
+            northing,
+            easting: easting - zone * 1_000_000.0,
+        })
+    }
+
+    /// 带号在前的东坐标
+    pub fn prefixed_easting(&self) -> f64 {
+        self.zone as f64 * 1_000_000.0 + self.easting
+    }
+
+    /// 反算为坐标
+    ///
+    /// # 参数
+    ///
+    /// - `ellipsoid` - 基准使用的椭球
+    ///
+    /// # 返回值
+    ///
+    /// 同一基准下的坐标，经度在[-180, 180)范围内，带号无效时返回错误
+    pub fn to_coordinate(&self, ellipsoid: &Ellipsoid) -> Result<Coordinate, GridError> {
+        if !(1..=self.zone_width.max_zone()).contains(&self.zone) {
+            return Err(GridError::InvalidZone(self.zone));
+        }
+        let (lat, dlon) = TransverseMercator::new(ellipsoid.a, ellipsoid.f, 1.0)
+            .reverse(self.easting - FALSE_EASTING, self.northing);
+        Ok(Coordinate {
+            lat,
+            lon: normalize_lon(self.zone_width.central_meridian(self.zone) + dlon),
+        })
+    }
+}
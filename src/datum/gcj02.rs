@@ -0,0 +1,231 @@
+//! GCJ-02和BD-09加密坐标系
+//!
+//! 国内地图数据使用的GCJ-02（“火星坐标”）在WGS-84的基础上加入了非线性偏移，
+//! BD-09在GCJ-02的基础上再次偏移，两者与WGS-84相差数百米。
+//! 正算为公开的标准算法；反算没有解析解，使用不动点迭代，残差不超过`INVERSE_TOLERANCE`。
+//! GCJ-02反算时迭代的是不设范围的偏移，边界附近的结果可能落在`is_in_china`的范围以外
+
+use crate::Coordinate;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// GCJ-02使用的Krasovsky椭球长半轴
+const A: f64 = 6378245.0;
+/// GCJ-02使用的Krasovsky椭球第一偏心率的平方
+const EE: f64 = 0.006_693_421_622_965_943;
+/// BD-09偏移使用的系数
+const X_PI: f64 = PI * 3000.0 / 180.0;
+
+/// 反算的残差上限（度），约合0.01毫米。
+/// 反算结果再正算后与输入的经纬度差都不超过该值
+pub const INVERSE_TOLERANCE: f64 = 1e-10;
+
+/// 反算的最大迭代次数，正常情况下3~5次即可收敛
+const MAX_ITERATIONS: usize = 30;
+
+/// 地图坐标系
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy, Clone)]
+pub enum MapSystem {
+    /// WGS-84，导航数据使用的坐标系
+    Wgs84,
+    /// GCJ-02，国内地图使用的加密坐标系
+    Gcj02,
+    /// BD-09，百度地图使用的加密坐标系
+    Bd09,
+}
+
+/// 坐标是否在GCJ-02的偏移范围内，范围以外的坐标不做偏移
+pub fn is_in_china(coord: &Coordinate) -> bool {
+    (72.004..=137.8347).contains(&coord.lon) && (0.8293..=55.8271).contains(&coord.lat)
+}
+
+fn transform_lat(x: f64, y: f64) -> f64 {
+    let mut ret = -100.0 + 2.0 * x + 3.0 * y + 0.2 * y * y + 0.1 * x * y + 0.2 * x.abs().sqrt();
+    ret += (20.0 * (6.0 * x * PI).sin() + 20.0 * (2.0 * x * PI).sin()) * 2.0 / 3.0;
+    ret += (20.0 * (y * PI).sin() + 40.0 * (y / 3.0 * PI).sin()) * 2.0 / 3.0;
+    ret += (160.0 * (y / 12.0 * PI).sin() + 320.0 * (y * PI / 30.0).sin()) * 2.0 / 3.0;
+    ret
+}
+
+fn transform_lon(x: f64, y: f64) -> f64 {
+    let mut ret = 300.0 + x + 2.0 * y + 0.1 * x * x + 0.1 * x * y + 0.1 * x.abs().sqrt();
+    ret += (20.0 * (6.0 * x * PI).sin() + 20.0 * (2.0 * x * PI).sin()) * 2.0 / 3.0;
+    ret += (20.0 * (x * PI).sin() + 40.0 * (x / 3.0 * PI).sin()) * 2.0 / 3.0;
+    ret += (150.0 * (x / 12.0 * PI).sin() + 300.0 * (x / 30.0 * PI).sin()) * 2.0 / 3.0;
+    ret
+}
+
+/// 通过不动点迭代求反算
+///
+/// # 参数
+///
+/// - `target` - 正算的结果
+/// - `start` - 初始值
+/// - `forward` - 正算
+fn inverse(
+    target: &Coordinate,
+    start: Coordinate,
+    forward: impl Fn(&Coordinate) -> Coordinate,
+) -> Coordinate {
+    let mut coord = start;
+    for _ in 0..MAX_ITERATIONS {
+        let image = forward(&coord);
+        let (dlat, dlon) = (target.lat - image.lat, target.lon - image.lon);
+        coord.lat += dlat;
+        coord.lon += dlon;
+        if dlat.abs() <= INVERSE_TOLERANCE && dlon.abs() <= INVERSE_TOLERANCE {
+            break;
+        }
+    }
+    coord
+}
+
+/// WGS-84转换为GCJ-02
+///
+/// # 参数
+///
+/// - `coord` - WGS-84坐标
+///
+/// # 返回值
+///
+/// GCJ-02坐标，不在国内范围时原样返回
+pub fn wgs84_to_gcj02(coord: &Coordinate) -> Coordinate {
+    if !is_in_china(coord) {
+        return *coord;
+    }
+    gcj02_offset(coord)
+}
+
+/// 不检查范围的GCJ-02偏移。
+/// 反算时使用，范围内外分段的正算在边界附近不连续，不动点迭代会来回振荡
+fn gcj02_offset(coord: &Coordinate) -> Coordinate {
+    let dlat = transform_lat(coord.lon - 105.0, coord.lat - 35.0);
+    let dlon = transform_lon(coord.lon - 105.0, coord.lat - 35.0);
+    let rad_lat = coord.lat.to_radians();
+    let magic = 1.0 - EE * rad_lat.sin() * rad_lat.sin();
+    let sqrt_magic = magic.sqrt();
+    Coordinate {
+        lat: coord.lat + dlat * 180.0 / ((A * (1.0 - EE)) / (magic * sqrt_magic) * PI),
+        lon: coord.lon + dlon * 180.0 / (A / sqrt_magic * rad_lat.cos() * PI),
+    }
+}
+
+/// GCJ-02转换为WGS-84，对不设范围的偏移迭代反算，残差不超过`INVERSE_TOLERANCE`
+///
+/// # 参数
+///
+/// - `coord` - GCJ-02坐标
+///
+/// # 返回值
+///
+/// WGS-84坐标，不在国内范围时原样返回。
+/// 范围边界附近的结果可能落在范围以外，此时`wgs84_to_gcj02`不做偏移，不能再正算回输入
+pub fn gcj02_to_wgs84(coord: &Coordinate) -> Coordinate {
+    if !is_in_china(coord) {
+        return *coord;
+    }
+    inverse(coord, *coord, gcj02_offset)
+}
+
+/// GCJ-02转换为BD-09
+///
+/// # 参数
+///
+/// - `coord` - GCJ-02坐标
+///
+/// # 返回值
+///
+/// BD-09坐标
+pub fn gcj02_to_bd09(coord: &Coordinate) -> Coordinate {
+    let (x, y) = (coord.lon, coord.lat);
+    let z = x.hypot(y) + 0.00002 * (y * X_PI).sin();
+    let theta = y.atan2(x) + 0.000003 * (x * X_PI).cos();
+    Coordinate {
+        lat: z * theta.sin() + 0.006,
+        lon: z * theta.cos() + 0.0065,
+    }
+}
+
+/// BD-09转换为GCJ-02，以常用的近似公式为初值迭代反算，残差不超过`INVERSE_TOLERANCE`
+///
+/// # 参数
+///
+/// - `coord` - BD-09坐标
+///
+/// # 返回值
+///
+/// GCJ-02坐标
+pub fn bd09_to_gcj02(coord: &Coordinate) -> Coordinate {
+    let (x, y) = (coord.lon - 0.0065, coord.lat - 0.006);
+    let z = x.hypot(y) - 0.00002 * (y * X_PI).sin();
+    let theta = y.atan2(x) - 0.000003 * (x * X_PI).cos();
+    let start = Coordinate {
+        lat: z * theta.sin(),
+        lon: z * theta.cos(),
+    };
+    inverse(coord, start, gcj02_to_bd09)
+}
+
+/// WGS-84转换为BD-09
+///
+/// # 参数
+///
+/// - `coord` - WGS-84坐标
+///
+/// # 返回值
+///
+/// BD-09坐标，不在国内范围时只做GCJ-02到BD-09的偏移
+pub fn wgs84_to_bd09(coord: &Coordinate) -> Coordinate {
+    gcj02_to_bd09(&wgs84_to_gcj02(coord))
+}
+
+/// BD-09转换为WGS-84，残差不超过`INVERSE_TOLERANCE`
+///
+/// # 参数
+///
+/// - `coord` - BD-09坐标
+///
+/// # 返回值
+///
+/// WGS-84坐标
+pub fn bd09_to_wgs84(coord: &Coordinate) -> Coordinate {
+    gcj02_to_wgs84(&bd09_to_gcj02(coord))
+}
+
+/// 在地图坐标系之间转换
+///
+/// # 参数
+///
+/// - `coord` - 坐标
+/// - `from` - 原坐标系
+/// - `to` - 目标坐标系
+///
+/// # 返回值
+///
+/// 目标坐标系中的坐标
+pub fn transform(coord: &Coordinate, from: MapSystem, to: MapSystem) -> Coordinate {
+    match (from, to) {
+        (MapSystem::Wgs84, MapSystem::Gcj02) => wgs84_to_gcj02(coord),
+        (MapSystem::Wgs84, MapSystem::Bd09) => wgs84_to_bd09(coord),
+        (MapSystem::Gcj02, MapSystem::Wgs84) => gcj02_to_wgs84(coord),
+        (MapSystem::Gcj02, MapSystem::Bd09) => gcj02_to_bd09(coord),
+        (MapSystem::Bd09, MapSystem::Wgs84) => bd09_to_wgs84(coord),
+        (MapSystem::Bd09, MapSystem::Gcj02) => bd09_to_gcj02(coord),
+        (MapSystem::Wgs84, MapSystem::Wgs84)
+        | (MapSystem::Gcj02, MapSystem::Gcj02)
+        | (MapSystem::Bd09, MapSystem::Bd09) => *coord,
+    }
+}
+
+/// 在地图坐标系之间转换多个坐标
+///
+/// # 参数
+///
+/// - `coords` - 坐标，转换结果直接写回
+/// - `from` - 原坐标系
+/// - `to` - 目标坐标系
+pub fn transform_slice(coords: &mut [Coordinate], from: MapSystem, to: MapSystem) {
+    for coord in coords {
+        *coord = transform(coord, from, to);
+    }
+}
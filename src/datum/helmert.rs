@@ -0,0 +1,198 @@
+//! 大地基准和七参数转换
+
+use crate::{Coordinate, Ellipsoid};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// 秒换算为弧度
+const ARC_SECOND: f64 = std::f64::consts::PI / (180.0 * 3600.0);
+
+/// 七参数（布尔莎）转换，采用位置矢量旋转约定（与PROJ的`towgs84`相同）
+#[derive(Debug, PartialEq, Serialize, Deserialize, Copy, Clone, Default)]
+pub struct Helmert {
+    /// X平移，单位为米
+    pub tx: f64,
+    /// Y平移，单位为米
+    pub ty: f64,
+    /// Z平移，单位为米
+    pub tz: f64,
+    /// 绕X轴旋转，单位为角秒
+    pub rx: f64,
+    /// 绕Y轴旋转，单位为角秒
+    pub ry: f64,
+    /// 绕Z轴旋转，单位为角秒
+    pub rz: f64,
+    /// 尺度变化，单位为ppm
+    pub ds: f64,
+}
+
+impl Helmert {
+    /// 不做任何变换的参数
+    pub const IDENTITY: Helmert = Helmert::translation(0.0, 0.0, 0.0);
+
+    /// 只有平移的三参数转换
+    pub const fn translation(tx: f64, ty: f64, tz: f64) -> Self {
+        Helmert {
+            tx,
+            ty,
+            tz,
+            rx: 0.0,
+            ry: 0.0,
+            rz: 0.0,
+            ds: 0.0,
+        }
+    }
+
+    /// 变换地心地固直角坐标
+    ///
+    /// # 参数
+    ///
+    /// - `xyz` - X、Y、Z，单位为米
+    ///
+    /// # 返回值
+    ///
+    /// 变换后的X、Y、Z
+    pub fn apply(&self, xyz: [f64; 3]) -> [f64; 3] {
+        let [x, y, z] = xyz;
+        let (rx, ry, rz) = (
+            self.rx * ARC_SECOND,
+            self.ry * ARC_SECOND,
+            self.rz * ARC_SECOND,
+        );
+        let s = 1.0 + self.ds * 1e-6;
+        [
+            self.tx + s * (x - rz * y + ry * z),
+            self.ty + s * (rz * x + y - rx * z),
+            self.tz + s * (-ry * x + rx * y + z),
+        ]
+    }
+
+    /// `apply`的逆变换，旋转矩阵取转置，对角秒级的旋转误差在微米级
+    ///
+    /// # 参数
+    ///
+    /// - `xyz` - 变换后的X、Y、Z，单位为米
+    ///
+    /// # 返回值
+    ///
+    /// 变换前的X、Y、Z
+    pub fn apply_inverse(&self, xyz: [f64; 3]) -> [f64; 3] {
+        let s = 1.0 + self.ds * 1e-6;
+        let (x, y, z) = (
+            (xyz[0] - self.tx) / s,
+            (xyz[1] - self.ty) / s,
+            (xyz[2] - self.tz) / s,
+        );
+        let (rx, ry, rz) = (
+            self.rx * ARC_SECOND,
+            self.ry * ARC_SECOND,
+            self.rz * ARC_SECOND,
+        );
+        [
+            x + rz * y - ry * z,
+            -rz * x + y + rx * z,
+            ry * x - rx * y + z,
+        ]
+    }
+}
+
+/// 基准转换时可能出现的错误
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DatumError {
+    /// 基准没有到WGS-84的转换参数
+    MissingParameters,
+}
+
+impl Display for DatumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DatumError::MissingParameters => write!(f, "datum has no parameters to WGS-84"),
+        }
+    }
+}
+
+impl std::error::Error for DatumError {}
+
+/// 大地基准
+#[derive(Debug, PartialEq, Serialize, Deserialize, Copy, Clone)]
+pub struct Datum {
+    /// 参考椭球
+    pub ellipsoid: Ellipsoid,
+    /// 转换到WGS-84的七参数，没有公开参数时为`None`
+    pub helmert: Option<Helmert>,
+}
+
+impl Datum {
+    /// WGS-84
+    pub const WGS84: Datum = Datum {
+        ellipsoid: Ellipsoid::WGS84,
+        helmert: Some(Helmert::IDENTITY),
+    };
+    /// 2000国家大地坐标系（CGCS2000），与WGS-84在厘米级一致，直接使用单位变换
+    pub const CGCS2000: Datum = Datum {
+        ellipsoid: Ellipsoid::CGCS2000,
+        helmert: Some(Helmert::IDENTITY),
+    };
+    /// 1954北京坐标系，使用EPSG公布的全国平均三参数，精度约为数米到十米
+    pub const BEIJING1954: Datum = Datum {
+        ellipsoid: Ellipsoid::KRASOVSKY,
+        helmert: Some(Helmert::translation(15.8, -154.4, -82.3)),
+    };
+    /// 1980西安坐标系，没有公开的转换参数，需要用`with_helmert`指定当地的参数
+    pub const XIAN1980: Datum = Datum {
+        ellipsoid: Ellipsoid::IAG1975,
+        helmert: None,
+    };
+
+    /// 使用指定的转换参数，例如测绘部门为当地发布的七参数
+    pub const fn with_helmert(self, helmert: Helmert) -> Self {
+        Datum {
+            ellipsoid: self.ellipsoid,
+            helmert: Some(helmert),
+        }
+    }
+
+    /// 转换为WGS-84坐标
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - 本基准下的坐标，大地高视为0，忽略高程变化带来的误差在毫米级
+    ///
+    /// # 返回值
+    ///
+    /// WGS-84坐标，没有转换参数时返回错误
+    pub fn to_wgs84(&self, coord: &Coordinate) -> Result<Coordinate, DatumError> {
+        let helmert = self.helmert.ok_or(DatumError::MissingParameters)?;
+        let xyz = helmert.apply(self.ellipsoid.to_ecef(coord, 0.0));
+        Ok(Ellipsoid::WGS84.from_ecef(xyz).0)
+    }
+
+    /// 由WGS-84坐标转换为本基准下的坐标
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - WGS-84坐标，大地高视为0
+    ///
+    /// # 返回值
+    ///
+    /// 本基准下的坐标，没有转换参数时返回错误
+    pub fn from_wgs84(&self, coord: &Coordinate) -> Result<Coordinate, DatumError> {
+        let helmert = self.helmert.ok_or(DatumError::MissingParameters)?;
+        let xyz = helmert.apply_inverse(Ellipsoid::WGS84.to_ecef(coord, 0.0));
+        Ok(self.ellipsoid.from_ecef(xyz).0)
+    }
+
+    /// 转换为另一个基准下的坐标，经过WGS-84中转
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - 本基准下的坐标
+    /// - `to` - 目标基准
+    ///
+    /// # 返回值
+    ///
+    /// 目标基准下的坐标，任一基准没有转换参数时返回错误
+    pub fn transform(&self, coord: &Coordinate, to: &Datum) -> Result<Coordinate, DatumError> {
+        to.from_wgs84(&self.to_wgs84(coord)?)
+    }
+}
@@ -0,0 +1,117 @@
+//! 参考椭球
+
+use crate::Coordinate;
+use serde::{Deserialize, Serialize};
+
+/// 参考椭球
+#[derive(Debug, PartialEq, Serialize, Deserialize, Copy, Clone)]
+pub struct Ellipsoid {
+    /// 长半轴，单位为米
+    pub a: f64,
+    /// 扁率
+    pub f: f64,
+}
+
+impl Ellipsoid {
+    /// WGS-84椭球
+    pub const WGS84: Ellipsoid = Ellipsoid::new(6378137.0, 1.0 / 298.257223563);
+    /// GRS 80椭球
+    pub const GRS80: Ellipsoid = Ellipsoid::new(6378137.0, 1.0 / 298.257222101);
+    /// CGCS2000椭球
+    ///
+    /// 与GRS 80的长半轴和自转角速度相同，地心引力常数GM不同；CGCS2000以扁率为定义常数、由扁率导出J2，
+    /// GRS 80以J2为定义常数、由J2导出扁率，两者的扁率相差极小，短半轴只相差约1e-10米，这里使用同一扁率
+    pub const CGCS2000: Ellipsoid = Ellipsoid::new(6378137.0, 1.0 / 298.257222101);
+    /// 克拉索夫斯基椭球，北京54坐标系使用
+    pub const KRASOVSKY: Ellipsoid = Ellipsoid::new(6378245.0, 1.0 / 298.3);
+    /// IAG 1975椭球，西安80坐标系使用
+    pub const IAG1975: Ellipsoid = Ellipsoid::new(6378140.0, 1.0 / 298.257);
+    /// 球，半径为IUGG平均地球半径
+    pub const SPHERE: Ellipsoid = Ellipsoid::sphere(6371008.8);
+
+    /// 创建椭球
+    ///
+    /// # 参数
+    ///
+    /// - `a` - 长半轴，单位为米
+    /// - `f` - 扁率，0表示球
+    pub const fn new(a: f64, f: f64) -> Self {
+        Ellipsoid { a, f }
+    }
+
+    /// 创建球
+    ///
+    /// # 参数
+    ///
+    /// - `radius` - 半径，单位为米
+    pub const fn sphere(radius: f64) -> Self {
+        Ellipsoid { a: radius, f: 0.0 }
+    }
+
+    /// 短半轴，单位为米
+    pub fn b(&self) -> f64 {
+        self.a * (1.0 - self.f)
+    }
+
+    /// 第一偏心率的平方
+    pub fn e2(&self) -> f64 {
+        self.f * (2.0 - self.f)
+    }
+
+    /// 大地坐标转换为地心地固直角坐标
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - 坐标
+    /// - `height` - 大地高，单位为米
+    ///
+    /// # 返回值
+    ///
+    /// X、Y、Z，单位为米
+    pub fn to_ecef(&self, coord: &Coordinate, height: f64) -> [f64; 3] {
+        let (sin_lat, cos_lat) = coord.lat.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = coord.lon.to_radians().sin_cos();
+        let e2 = self.e2();
+        let n = self.a / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        [
+            (n + height) * cos_lat * cos_lon,
+            (n + height) * cos_lat * sin_lon,
+            (n * (1.0 - e2) + height) * sin_lat,
+        ]
+    }
+
+    /// 地心地固直角坐标转换为大地坐标，使用Bowring公式，地表附近误差在微米级
+    ///
+    /// # 参数
+    ///
+    /// - `xyz` - X、Y、Z，单位为米
+    ///
+    /// # 返回值
+    ///
+    /// 坐标和大地高（米）
+    pub fn from_ecef(&self, xyz: [f64; 3]) -> (Coordinate, f64) {
+        let [x, y, z] = xyz;
+        let (a, b, e2) = (self.a, self.b(), self.e2());
+        let ep2 = e2 / (1.0 - e2);
+        let p = x.hypot(y);
+
+        let (sin_t, cos_t) = (z * a).atan2(p * b).sin_cos();
+        let lat = (z + ep2 * b * sin_t.powi(3)).atan2(p - e2 * a * cos_t.powi(3));
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let height = p * cos_lat + z * sin_lat - a * (1.0 - e2 * sin_lat * sin_lat).sqrt();
+
+        (
+            Coordinate {
+                lat: lat.to_degrees(),
+                lon: y.atan2(x).to_degrees(),
+            },
+            height,
+        )
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Ellipsoid::WGS84
+    }
+}
@@ -2,4 +2,11 @@ pub mod coordinate;
 
 pub mod algorithm;
 
+pub mod datum;
+
+pub mod ellipsoid;
+
+pub mod magnetic;
+
 pub use coordinate::Coordinate;
+pub use ellipsoid::Ellipsoid;
@@ -0,0 +1,5 @@
+//! 地磁场模型和磁偏角
+
+mod wmm;
+
+pub use wmm::{decimal_year, get_mag_var, MagneticField, MagneticModel, MagneticModelError};
@@ -0,0 +1,338 @@
+//! 世界地磁模型（World Magnetic Model）
+//!
+//! 地磁场的位函数展开为球谐函数，系数随时间线性变化。
+//! 内置NOAA发布的WMM2025系数，有效期为2025.0至2030.0，其他模型可以用`MagneticModel::from_cof`读取。
+//! 超出有效期时按年变化率外推，不报错，可以用`MagneticModel::is_valid`检查
+
+use crate::{Coordinate, Ellipsoid};
+use std::fmt::{self, Display, Formatter};
+use std::sync::OnceLock;
+
+/// 内置的WMM2025系数文件
+const WMM_COF: &str = include_str!("WMM.COF");
+/// 地磁参考球半径，单位为米
+const REFERENCE_RADIUS: f64 = 6371200.0;
+/// WMM系数的有效期，单位为年
+const VALIDITY_YEARS: f64 = 5.0;
+
+/// 读取系数文件时可能出现的错误
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum MagneticModelError {
+    /// 第一行不是有效的历元和模型名称
+    InvalidHeader,
+    /// 系数行格式错误，附带行号（从1开始）
+    InvalidLine(usize),
+    /// 没有系数
+    MissingCoefficients,
+}
+
+impl Display for MagneticModelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MagneticModelError::InvalidHeader => write!(f, "invalid coefficient file header"),
+            MagneticModelError::InvalidLine(line) => {
+                write!(f, "invalid coefficient line {}", line)
+            }
+            MagneticModelError::MissingCoefficients => write!(f, "no coefficients"),
+        }
+    }
+}
+
+impl std::error::Error for MagneticModelError {}
+
+/// 地磁场矢量，单位为纳特斯拉
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct MagneticField {
+    /// 北向分量X
+    pub north: f64,
+    /// 东向分量Y
+    pub east: f64,
+    /// 垂直向下分量Z
+    pub down: f64,
+}
+
+impl MagneticField {
+    /// 水平强度H
+    pub fn horizontal(&self) -> f64 {
+        self.north.hypot(self.east)
+    }
+
+    /// 总强度F
+    pub fn total(&self) -> f64 {
+        self.horizontal().hypot(self.down)
+    }
+
+    /// 磁偏角D，单位为度，东正西负
+    pub fn declination(&self) -> f64 {
+        self.east.atan2(self.north).to_degrees()
+    }
+
+    /// 磁倾角I，单位为度，向下为正
+    pub fn inclination(&self) -> f64 {
+        self.down.atan2(self.horizontal()).to_degrees()
+    }
+}
+
+/// 球谐地磁场模型
+#[derive(Debug, PartialEq, Clone)]
+pub struct MagneticModel {
+    /// 模型名称，例如`WMM-2025`
+    pub name: String,
+    /// 系数的历元，单位为年
+    pub epoch: f64,
+    /// 最大阶数
+    degree: usize,
+    /// 按`n * (n + 1) / 2 + m`排列的主磁场系数g、h和年变化率，单位为nT和nT/年
+    g: Vec<f64>,
+    h: Vec<f64>,
+    g_dot: Vec<f64>,
+    h_dot: Vec<f64>,
+}
+
+/// 系数的序号
+fn index(n: usize, m: usize) -> usize {
+    n * (n + 1) / 2 + m
+}
+
+/// 处理系数行`n m g h g_dot h_dot`
+fn parse_row(line: &str) -> Option<(usize, usize, [f64; 4])> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let [n, m, g, h, g_dot, h_dot] = fields[..] else {
+        return None;
+    };
+    let (n, m): (usize, usize) = (n.parse().ok()?, m.parse().ok()?);
+    if n == 0 || m > n {
+        return None;
+    }
+    let row = [
+        g.parse().ok()?,
+        h.parse().ok()?,
+        g_dot.parse().ok()?,
+        h_dot.parse().ok()?,
+    ];
+    Some((n, m, row))
+}
+
+impl MagneticModel {
+    /// 内置的WMM2025模型
+    pub fn wmm() -> &'static MagneticModel {
+        static WMM: OnceLock<MagneticModel> = OnceLock::new();
+        WMM.get_or_init(|| MagneticModel::from_cof(WMM_COF).expect("built-in WMM.COF is valid"))
+    }
+
+    /// 读取NOAA格式的系数文件（`WMM.COF`）
+    ///
+    /// # 参数
+    ///
+    /// - `s` - 文件内容，第一行为历元和模型名称，之后每行为`n m g h g_dot h_dot`，以一行`9999…`结束
+    ///
+    /// # 返回值
+    ///
+    /// 模型，格式错误时返回错误
+    pub fn from_cof(s: &str) -> Result<Self, MagneticModelError> {
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        let (_, header) = lines.next().ok_or(MagneticModelError::InvalidHeader)?;
+        let mut header = header.split_whitespace();
+        let epoch = header
+            .next()
+            .and_then(|epoch| epoch.parse().ok())
+            .ok_or(MagneticModelError::InvalidHeader)?;
+        let name = header
+            .next()
+            .ok_or(MagneticModelError::InvalidHeader)?
+            .to_string();
+
+        let mut rows = Vec::new();
+        for (i, line) in lines {
+            if line.trim_start().starts_with("9999") {
+                break;
+            }
+            rows.push(parse_row(line).ok_or(MagneticModelError::InvalidLine(i + 1))?);
+        }
+
+        let degree = rows
+            .iter()
+            .map(|(n, _, _)| *n)
+            .max()
+            .ok_or(MagneticModelError::MissingCoefficients)?;
+        let len = index(degree, degree) + 1;
+        let mut model = MagneticModel {
+            name,
+            epoch,
+            degree,
+            g: vec![0.0; len],
+            h: vec![0.0; len],
+            g_dot: vec![0.0; len],
+            h_dot: vec![0.0; len],
+        };
+        for (n, m, [g, h, g_dot, h_dot]) in rows {
+            let k = index(n, m);
+            model.g[k] = g;
+            model.h[k] = h;
+            model.g_dot[k] = g_dot;
+            model.h_dot[k] = h_dot;
+        }
+        Ok(model)
+    }
+
+    /// 最大阶数
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// 有效期的结束时间，WMM的有效期为历元之后5年
+    pub fn valid_until(&self) -> f64 {
+        self.epoch + VALIDITY_YEARS
+    }
+
+    /// 判断年份是否在有效期`[epoch, valid_until)`内，有效期外的结果由年变化率外推得到，误差随时间增大
+    ///
+    /// # 参数
+    ///
+    /// - `year` - 小数表示的年份
+    pub fn is_valid(&self, year: f64) -> bool {
+        (self.epoch..self.valid_until()).contains(&year)
+    }
+
+    /// 计算地磁场矢量
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - WGS-84坐标
+    /// - `altitude` - WGS-84椭球高，单位为米
+    /// - `year` - 小数表示的年份，例如2020.5，参见`decimal_year`。超出模型有效期时按年变化率外推，误差随时间增大
+    ///
+    /// # 返回值
+    ///
+    /// 大地坐标系下的北、东、下分量
+    pub fn field(&self, coord: &Coordinate, altitude: f64, year: f64) -> MagneticField {
+        let dt = year - self.epoch;
+        let lambda = coord.lon.to_radians();
+
+        // 地心球坐标
+        let [x, y, z] = Ellipsoid::WGS84.to_ecef(coord, altitude);
+        let r = x.hypot(y).hypot(z);
+        let phi = (z / r).asin();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        // 极点处取极限，避免除以零
+        let cos_phi = cos_phi.max(1e-12);
+
+        let (p, dp) = self.legendre(sin_phi, cos_phi);
+        let (mut b_r, mut b_theta, mut b_lambda) = (0.0, 0.0, 0.0);
+        for n in 1..=self.degree {
+            let ratio = (REFERENCE_RADIUS / r).powi(n as i32 + 2);
+            for m in 0..=n {
+                let k = index(n, m);
+                let g = self.g[k] + dt * self.g_dot[k];
+                let h = self.h[k] + dt * self.h_dot[k];
+                let (sin_ml, cos_ml) = (m as f64 * lambda).sin_cos();
+                let c = g * cos_ml + h * sin_ml;
+                b_r += ratio * (n + 1) as f64 * c * p[k];
+                b_theta += ratio * c * dp[k];
+                b_lambda += ratio * m as f64 * (g * sin_ml - h * cos_ml) * p[k];
+            }
+        }
+
+        // 地心坐标系的北、东、下分量，再旋转到大地坐标系
+        let north = -b_theta;
+        let east = b_lambda / cos_phi;
+        let down = -b_r;
+        let psi = phi - coord.lat.to_radians();
+        let (sin_psi, cos_psi) = psi.sin_cos();
+        MagneticField {
+            north: north * cos_psi - down * sin_psi,
+            east,
+            down: north * sin_psi + down * cos_psi,
+        }
+    }
+
+    /// 计算磁偏角
+    ///
+    /// # 参数
+    ///
+    /// - `coord` - WGS-84坐标
+    /// - `altitude` - WGS-84椭球高，单位为米
+    /// - `year` - 小数表示的年份
+    ///
+    /// # 返回值
+    ///
+    /// 磁偏角，单位为度，东正西负，与`get_colocated_coord`等函数的`mag_var`一致。极点处没有意义
+    pub fn declination(&self, coord: &Coordinate, altitude: f64, year: f64) -> f64 {
+        self.field(coord, altitude, year).declination()
+    }
+
+    /// 施密特半归一化的缔合勒让德函数P(n, m)及其对地心纬度的导数
+    fn legendre(&self, sin_phi: f64, cos_phi: f64) -> (Vec<f64>, Vec<f64>) {
+        let len = index(self.degree, self.degree) + 1;
+        let mut p = vec![0.0; len];
+        let mut dp = vec![0.0; len];
+        p[0] = 1.0;
+        for n in 1..=self.degree {
+            let nf = n as f64;
+            for m in 0..=n {
+                let k = index(n, m);
+                if m == n {
+                    // 对角线递推
+                    let prev = index(n - 1, n - 1);
+                    let factor = if n == 1 {
+                        1.0
+                    } else {
+                        ((2.0 * nf - 1.0) / (2.0 * nf)).sqrt()
+                    };
+                    p[k] = factor * cos_phi * p[prev];
+                    dp[k] = factor * (cos_phi * dp[prev] - sin_phi * p[prev]);
+                } else {
+                    let mf = m as f64;
+                    let prev = index(n - 1, m);
+                    let a = (2.0 * nf - 1.0) / ((nf * nf - mf * mf).sqrt());
+                    p[k] = a * sin_phi * p[prev];
+                    dp[k] = a * (sin_phi * dp[prev] + cos_phi * p[prev]);
+                    if n >= m + 2 {
+                        let prev2 = index(n - 2, m);
+                        let b = (((nf - 1.0) * (nf - 1.0) - mf * mf) / (nf * nf - mf * mf)).sqrt();
+                        p[k] -= b * p[prev2];
+                        dp[k] -= b * dp[prev2];
+                    }
+                }
+            }
+        }
+        (p, dp)
+    }
+}
+
+/// 将日期转换为小数表示的年份，例如2020年7月2日为2020.5
+///
+/// # 参数
+///
+/// - `year` - 年
+/// - `month` - 月，1~12
+/// - `day` - 日，1~31
+pub fn decimal_year(year: i32, month: u32, day: u32) -> f64 {
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_year = if leap { 366.0 } else { 365.0 };
+    const CUMULATIVE: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let month = month.clamp(1, 12);
+    let mut day_of_year = CUMULATIVE[month as usize - 1] + day;
+    if leap && month > 2 {
+        day_of_year += 1;
+    }
+    year as f64 + (day_of_year as f64 - 1.0) / days_in_year
+}
+
+/// 获取内置WMM模型下海平面处的磁偏角
+///
+/// # 参数
+///
+/// - `coord` - WGS-84坐标
+/// - `year` - 小数表示的年份，参见`decimal_year`。内置模型的有效期为2025.0至2030.0，
+///   超出时不报错而是按年变化率外推，误差随时间增大，需要时先用`MagneticModel::wmm().is_valid(year)`检查
+///
+/// # 返回值
+///
+/// 磁偏角，单位为度，东正西负
+pub fn get_mag_var(coord: &Coordinate, year: f64) -> f64 {
+    MagneticModel::wmm().declination(coord, 0.0, year)
+}
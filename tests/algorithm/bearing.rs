@@ -0,0 +1,122 @@
+use navdata_components::algorithm::bearing::{MagneticBearing, TrueBearing, TurnDirection};
+use navdata_components::algorithm::measurement::{
+    get_angle, get_final_bearing, get_initial_bearing, get_magnetic_bearing, get_reverse_bearing,
+    normalize_bearing,
+};
+use navdata_components::algorithm::position_transformation::{
+    get_colocated_coord, get_colocated_coord_true,
+};
+use navdata_components::coordinate::Coordinate;
+
+fn coord(lat: f64, lon: f64) -> Coordinate {
+    Coordinate { lat, lon }
+}
+
+#[test]
+fn test_normalize_bearing() {
+    assert_eq!(normalize_bearing(0.0), 0.0);
+    assert_eq!(normalize_bearing(360.0), 0.0);
+    assert_eq!(normalize_bearing(-90.0), 270.0);
+    assert_eq!(normalize_bearing(725.0), 5.0);
+    assert_eq!(normalize_bearing(-1e-17), 0.0);
+}
+
+#[test]
+fn test_long_leg() {
+    // 参考值来自GeographicLib
+    let raw = coord(-41.32, 174.81);
+    let target = coord(40.96, -5.50);
+    assert!((get_initial_bearing(&raw, &target).degrees() - 161.06766998615882).abs() < 1e-10);
+    assert!((get_final_bearing(&raw, &target).degrees() - 18.825195123248392).abs() < 1e-10);
+    assert!((get_reverse_bearing(&raw, &target).degrees() - 198.8251951232484).abs() < 1e-10);
+}
+
+#[test]
+fn test_reverse_bearing() {
+    let raw = coord(40.0, 116.0);
+    let target = coord(41.25, 114.365);
+    let reverse = get_reverse_bearing(&raw, &target);
+    assert!((reverse - get_initial_bearing(&target, &raw)).abs() < 1e-9);
+    // 航线较长时起始和最终方位角不同
+    assert!((get_final_bearing(&raw, &target) - get_initial_bearing(&raw, &target)).abs() > 1.0);
+}
+
+#[test]
+fn test_cardinal() {
+    let raw = coord(10.0, 20.0);
+    assert_eq!(get_initial_bearing(&raw, &coord(11.0, 20.0)), 0.0);
+    assert_eq!(get_initial_bearing(&raw, &coord(9.0, 20.0)), 180.0);
+    assert_eq!(
+        get_initial_bearing(&coord(0.0, 0.0), &coord(0.0, -1.0)),
+        270.0
+    );
+}
+
+#[test]
+fn test_magnetic_bearing() {
+    let raw = coord(10.0, 20.0);
+    let target = coord(11.0, 20.0);
+    // 东偏时磁方位角小于真方位角，结果回绕到[0, 360)
+    assert_eq!(get_magnetic_bearing(&raw, &target, 7.0), 353.0);
+    assert_eq!(get_magnetic_bearing(&raw, &target, -7.0), 7.0);
+    assert_eq!(get_angle(&raw, &target, 360.0), 0.0);
+    for mag_var in [-400.0, -180.0, -5.5, 0.0, 5.5, 180.0, 400.0] {
+        let bearing = get_magnetic_bearing(&coord(40.0, 116.0), &coord(39.0, 117.0), mag_var);
+        assert!((0.0..360.0).contains(&bearing.degrees()));
+    }
+}
+
+#[test]
+fn test_bearing_types() {
+    assert_eq!(TrueBearing::new(-90.0), 270.0);
+    assert_eq!(TrueBearing::new(720.0).degrees(), 0.0);
+    assert_eq!(MagneticBearing::new(10.0).reciprocal(), 190.0);
+    assert_eq!(TrueBearing::new(350.0) + 20.0, TrueBearing::new(10.0));
+    assert_eq!(TrueBearing::new(10.0) - 20.0, TrueBearing::new(350.0));
+
+    // 有符号的差取较小的一侧
+    let (a, b) = (TrueBearing::new(350.0), TrueBearing::new(10.0));
+    assert_eq!(a.angle_to(b), 20.0);
+    assert_eq!(b.angle_to(a), -20.0);
+    assert_eq!(b - a, 20.0);
+    assert_eq!(a.turn_to(b), TurnDirection::Right);
+    assert_eq!(b.turn_to(a), TurnDirection::Left);
+    assert_eq!(a.turn_to(a), TurnDirection::Straight);
+    assert_eq!(a.turn_to(a.reciprocal()), TurnDirection::Right);
+
+    // 真方位 = 磁方位 + 磁偏角
+    let magnetic = MagneticBearing::new(45.0);
+    assert_eq!(magnetic.to_true(-7.0), 38.0);
+    assert_eq!(TrueBearing::new(38.0).to_magnetic(-7.0), magnetic);
+    assert_eq!(TrueBearing::new(3.0).to_magnetic(7.0), 356.0);
+}
+
+#[test]
+fn test_bearing_arguments() {
+    let raw = coord(40.0, 116.0);
+    // 磁方位和磁偏角换算为真方位后与直接使用真方位的结果相同
+    assert_eq!(
+        get_colocated_coord(&raw, MagneticBearing::new(45.0), 10000.0, -7.0),
+        get_colocated_coord_true(&raw, TrueBearing::new(38.0), 10000.0)
+    );
+
+    let target = coord(39.0, 117.0);
+    assert_eq!(
+        get_magnetic_bearing(&raw, &target, -7.0).to_true(-7.0),
+        get_initial_bearing(&raw, &target)
+    );
+}
+
+#[test]
+fn test_bearing_serde() {
+    // 反序列化时规范化到[0, 360)
+    let bearing: TrueBearing = serde_json::from_str("725.0").unwrap();
+    assert_eq!(bearing, 5.0);
+    assert_eq!(serde_json::to_string(&bearing).unwrap(), "5.0");
+    let bearing: MagneticBearing = serde_json::from_str("-10.0").unwrap();
+    assert_eq!(bearing, 350.0);
+    assert_eq!(
+        serde_json::from_str::<MagneticBearing>(&serde_json::to_string(&bearing).unwrap()).unwrap(),
+        bearing
+    );
+}
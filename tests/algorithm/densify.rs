@@ -0,0 +1,101 @@
+use navdata_components::algorithm::densify::{
+    densify, densify_ring, get_intermediate_coord, get_intermediate_coords, get_midpoint,
+    MAX_DENSIFY_SEGMENTS,
+};
+use navdata_components::algorithm::measurement::{get_distance, get_initial_bearing};
+use navdata_components::algorithm::region::is_point_in_area;
+use navdata_components::coordinate::Coordinate;
+
+fn coord(lat: f64, lon: f64) -> Coordinate {
+    Coordinate { lat, lon }
+}
+
+#[test]
+fn test_intermediate_coord() {
+    let raw = coord(40.0, 116.0);
+    let target = coord(31.0, 121.5);
+    let total = get_distance(&raw, &target);
+
+    assert!(get_distance(&get_intermediate_coord(&raw, &target, 0.0), &raw) < 1e-6);
+    assert!(get_distance(&get_intermediate_coord(&raw, &target, 1.0), &target) < 1e-6);
+
+    let point = get_intermediate_coord(&raw, &target, 0.25);
+    assert!((get_distance(&raw, &point) - total * 0.25).abs() < 1e-6);
+    assert!((get_distance(&point, &target) - total * 0.75).abs() < 1e-6);
+
+    let midpoint = get_midpoint(&raw, &target);
+    assert!((get_distance(&raw, &midpoint) - get_distance(&midpoint, &target)).abs() < 1e-6);
+}
+
+#[test]
+fn test_intermediate_coords() {
+    let raw = coord(51.47, -0.45);
+    let target = coord(40.64, -73.78);
+    let total = get_distance(&raw, &target);
+    let coords = get_intermediate_coords(&raw, &target, 9);
+    assert_eq!(coords.len(), 9);
+
+    // 各点都在同一条测地线上，间隔相等
+    let bearing = get_initial_bearing(&raw, &target);
+    let mut last = raw;
+    for point in coords.iter().chain([&target]) {
+        assert!((get_distance(&last, point) - total / 10.0).abs() < 1e-6);
+        assert!((get_initial_bearing(&raw, point) - bearing).abs() < 1e-9);
+        last = *point;
+    }
+
+    assert!(get_intermediate_coords(&raw, &target, 0).is_empty());
+}
+
+#[test]
+fn test_densify() {
+    let raw = coord(0.0, 179.0);
+    let target = coord(0.0, -179.0);
+    let coords = densify(&raw, &target, 50_000.0);
+    // 222.6千米，每50千米一点
+    assert_eq!(coords.len(), 6);
+    assert_eq!(coords[0], raw);
+    assert_eq!(coords[5], target);
+    for pair in coords.windows(2) {
+        assert!(get_distance(&pair[0], &pair[1]) <= 50_000.0 + 1e-6);
+        assert!(pair[0].lon >= -180.0 && pair[0].lon < 180.0);
+    }
+    assert!((get_distance(&coords[3], &coords[4]) - 50_000.0).abs() < 1e-6);
+
+    assert_eq!(densify(&raw, &target, 0.0), vec![raw, target]);
+    assert_eq!(densify(&raw, &raw, 1000.0), vec![raw, raw]);
+
+    // 间隔过小时等分成MAX_DENSIFY_SEGMENTS段
+    let coords = densify(&raw, &target, 1e-6);
+    assert_eq!(coords.len(), MAX_DENSIFY_SEGMENTS + 1);
+    let step = get_distance(&raw, &target) / MAX_DENSIFY_SEGMENTS as f64;
+    assert!((get_distance(&coords[0], &coords[1]) - step).abs() < 1e-6);
+    assert_eq!(
+        densify(&raw, &target, f64::MIN_POSITIVE).len(),
+        MAX_DENSIFY_SEGMENTS + 1
+    );
+}
+
+#[test]
+fn test_densify_ring() {
+    let ring = [
+        coord(60.0, 0.0),
+        coord(60.0, 60.0),
+        coord(70.0, 60.0),
+        coord(70.0, 0.0),
+    ];
+    // 60°N两点之间的测地线向北凸出到约63.4°N，该点实际在区域外
+    let point = coord(62.0, 30.0);
+    assert!(is_point_in_area(&point, &ring));
+
+    let dense = densify_ring(&ring, 10_000.0);
+    assert!(!is_point_in_area(&point, &dense));
+    assert!(is_point_in_area(&coord(65.0, 30.0), &dense));
+    assert_eq!(dense[0], ring[0]);
+    assert_ne!(dense.last(), Some(&ring[0]));
+    for (i, pair) in dense.windows(2).enumerate() {
+        assert!(get_distance(&pair[0], &pair[1]) <= 10_000.0 + 1e-6, "{}", i);
+    }
+
+    assert!(densify_ring(&[], 1000.0).is_empty());
+}
@@ -0,0 +1,85 @@
+use lazy_static::lazy_static;
+use navdata_components::algorithm::bearing::{MagneticBearing, TrueBearing};
+use navdata_components::algorithm::measurement::{
+    get_angle, get_angle_with, get_circle_distance, get_circle_distance_with, get_distance,
+    get_distance_with,
+};
+use navdata_components::algorithm::position_transformation::{
+    get_colocated_coord, get_colocated_coord_true_with, get_colocated_coord_with,
+};
+use navdata_components::coordinate::Coordinate;
+use navdata_components::Ellipsoid;
+
+lazy_static! {
+    static ref RAW: Coordinate = Coordinate {
+        lat: 40.0,
+        lon: 116.0
+    };
+    static ref TARGET: Coordinate = Coordinate {
+        lat: 41.25,
+        lon: 114.365
+    };
+}
+
+#[test]
+fn test_defaults_to_wgs84() {
+    let wgs84 = Ellipsoid::WGS84;
+    assert_eq!(
+        get_distance(&RAW, &TARGET),
+        get_distance_with(&RAW, &TARGET, &wgs84)
+    );
+    assert_eq!(
+        get_circle_distance(&RAW, &TARGET),
+        get_circle_distance_with(&RAW, &TARGET, &wgs84)
+    );
+    assert_eq!(
+        get_angle(&RAW, &TARGET, 5.0),
+        get_angle_with(&RAW, &TARGET, 5.0, &wgs84)
+    );
+    assert_eq!(
+        get_colocated_coord(&RAW, MagneticBearing::new(112.0), 36502.0, 5.0),
+        get_colocated_coord_with(&RAW, MagneticBearing::new(112.0), 36502.0, 5.0, &wgs84)
+    );
+}
+
+#[test]
+fn test_wgs84_minor_axis() {
+    assert!((Ellipsoid::WGS84.b() - 6356752.314245).abs() < 1e-6);
+}
+
+#[test]
+fn test_sphere() {
+    let sphere = Ellipsoid::SPHERE;
+    let distance = get_distance_with(&RAW, &TARGET, &sphere);
+    assert!((distance - get_circle_distance_with(&RAW, &TARGET, &sphere)).abs() < 1e-6);
+
+    let sphere = Ellipsoid::sphere(6378137.0);
+    assert!(
+        (get_distance_with(&RAW, &TARGET, &sphere) - get_circle_distance(&RAW, &TARGET)).abs()
+            < 1e-6
+    );
+}
+
+#[test]
+fn test_other_ellipsoids() {
+    let wgs84 = get_distance(&RAW, &TARGET);
+    // GRS 80与WGS-84的扁率只有极小差异
+    let grs80 = get_distance_with(&RAW, &TARGET, &Ellipsoid::GRS80);
+    assert!((grs80 - wgs84).abs() < 1e-3);
+    assert_eq!(
+        grs80,
+        get_distance_with(&RAW, &TARGET, &Ellipsoid::CGCS2000)
+    );
+
+    let krasovsky = get_distance_with(&RAW, &TARGET, &Ellipsoid::KRASOVSKY);
+    assert!((krasovsky - wgs84).abs() > 1.0 && (krasovsky - wgs84).abs() < 50.0);
+
+    let target = get_colocated_coord_true_with(
+        &RAW,
+        TrueBearing::new(300.0),
+        krasovsky,
+        &Ellipsoid::KRASOVSKY,
+    );
+    let distance = get_distance_with(&RAW, &target, &Ellipsoid::KRASOVSKY);
+    assert!((distance - krasovsky).abs() < 1e-4);
+}
@@ -0,0 +1,308 @@
+use navdata_components::algorithm::geodesic::Geodesic;
+use navdata_components::algorithm::measurement::get_distance;
+use navdata_components::coordinate::Coordinate;
+use navdata_components::Ellipsoid;
+
+fn coord(lat: f64, lon: f64) -> Coordinate {
+    Coordinate { lat, lon }
+}
+
+// 参考值来自GeographicLib
+#[test]
+fn test_inverse() {
+    let geodesic = Geodesic::default();
+    let result = geodesic.inverse(&coord(-41.32, 174.81), &coord(40.96, -5.50));
+    assert!((result.distance - 19959679.26735382).abs() < 1e-8);
+    assert!((result.initial_azimuth - 161.06766998615882).abs() < 1e-10);
+    assert!((result.final_azimuth - 18.825195123248392).abs() < 1e-10);
+
+    let result = geodesic.inverse(&coord(40.6, -73.8), &coord(51.6, -0.5));
+    assert!((result.distance - 5551759.400319).abs() < 1e-6);
+    assert!((result.initial_azimuth - 51.198882845579824).abs() < 1e-10);
+    assert!((result.final_azimuth - 107.82177673551426).abs() < 1e-10);
+}
+
+#[test]
+fn test_direct() {
+    let geodesic = Geodesic::default();
+    let result = geodesic.direct(&coord(-32.06, 115.74), 225.0, 20000e3);
+    assert!((result.coord.lat - 32.11195529).abs() < 5e-9);
+    assert!((result.coord.lon + 63.95925278).abs() < 5e-9);
+
+    let result = geodesic.direct(
+        &coord(-41.32, 174.81),
+        161.06766998615882,
+        19959679.26735382,
+    );
+    assert!((result.coord.lat - 40.96).abs() < 1e-12);
+    assert!((result.coord.lon + 5.50).abs() < 1e-12);
+    assert!((result.final_azimuth - 18.825195123248392).abs() < 1e-10);
+}
+
+#[test]
+fn test_special_cases() {
+    let geodesic = Geodesic::default();
+
+    // 赤道上1度
+    let result = geodesic.inverse(&coord(0.0, 0.0), &coord(0.0, 1.0));
+    assert!((result.distance - 111319.49079327357).abs() < 1e-8);
+    assert_eq!(result.initial_azimuth, 90.0);
+
+    // 赤道上的对跖点，测地线经过极点
+    let result = geodesic.inverse(&coord(0.0, 0.0), &coord(0.0, 180.0));
+    assert!((result.distance - 20003931.4586254).abs() < 1e-6);
+    assert_eq!(result.initial_azimuth, 0.0);
+
+    // 两极之间
+    let result = geodesic.inverse(&coord(90.0, 0.0), &coord(-90.0, 0.0));
+    assert!((result.distance - 20003931.4586254).abs() < 1e-6);
+
+    // 重合点
+    assert_eq!(
+        geodesic
+            .inverse(&coord(10.0, 20.0), &coord(10.0, 20.0))
+            .distance,
+        0.0
+    );
+}
+
+#[test]
+fn test_nearly_antipodal() {
+    // Vincenty公式在这里不收敛
+    let raw = coord(0.0, 0.0);
+    let target = coord(0.5, 179.7);
+    assert!((get_distance(&raw, &target) - 19944127.421).abs() < 1e-3);
+
+    let geodesic = Geodesic::default();
+    for i in 0..=100 {
+        let target = coord(-0.5 + i as f64 * 0.01, 179.5 + i as f64 * 0.005);
+        let inverse = geodesic.inverse(&raw, &target);
+        assert!(inverse.distance.is_finite(), "{:?}", target);
+
+        let direct = geodesic.direct(&raw, inverse.initial_azimuth, inverse.distance);
+        assert!(
+            geodesic.inverse(&direct.coord, &target).distance < 1e-6,
+            "{:?}",
+            target
+        );
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    for ellipsoid in [Ellipsoid::WGS84, Ellipsoid::KRASOVSKY, Ellipsoid::SPHERE] {
+        let geodesic = Geodesic::new(&ellipsoid);
+        for lat1 in (-90..=90).step_by(30) {
+            for lat2 in (-90..=90).step_by(15) {
+                for lon2 in (-180..=180).step_by(45) {
+                    let raw = coord(lat1 as f64, 10.0);
+                    let target = coord(lat2 as f64, lon2 as f64);
+                    let inverse = geodesic.inverse(&raw, &target);
+                    let direct = geodesic.direct(&raw, inverse.initial_azimuth, inverse.distance);
+                    let error = geodesic.inverse(&direct.coord, &target).distance;
+                    assert!(error < 1e-6, "{:?} {:?} {}", raw, target, error);
+                }
+            }
+        }
+    }
+}
+
+/// Karney发布的GeodTest.dat（https://doi.org/10.5281/zenodo.32156）的前100行，
+/// 每行为`lat1 lon1 azi1 lat2 lon2 azi2 s12 a12 m12 S12`
+const GEODTEST: &str = include_str!("../data/GeodTest-100.dat");
+/// 补充近对跖、短距离、极点和赤道等情况的参考值，生成方法见`tests/data/geodtest.py`
+const GEODTEST_GENERATED: &str = include_str!("../data/geodtest.dat");
+
+/// 两个方位角之差，单位为弧度
+fn azimuth_error(a: f64, b: f64) -> f64 {
+    let diff = (a - b).rem_euclid(360.0);
+    diff.min(360.0 - diff).to_radians()
+}
+
+/// 对每一行做反算和正算并检查误差
+///
+/// 参照GeodTest的误差度量：距离、位置和正算约化长度的误差不超过15纳米；
+/// 方位角误差乘以约化长度（正算终点方位角乘以纬圈半径）换算为位置误差，
+/// 短距离和终点接近极点时方位角本身是病态的，其余情况直接检查方位角
+fn check_geodtest(data: &str) {
+    let geodesic = Geodesic::default();
+    for (i, line) in data.lines().enumerate() {
+        let row: Vec<f64> = line
+            .split_whitespace()
+            .map(|value| value.parse().unwrap())
+            .collect();
+        let [lat1, lon1, azi1, lat2, lon2, azi2, s12, ..] = row[..] else {
+            panic!("invalid row {}", i + 1);
+        };
+        let raw = coord(lat1, lon1);
+        let target = coord(lat2, lon2);
+
+        let inverse = geodesic.inverse(&raw, &target);
+        assert!(
+            (inverse.distance - s12).abs() <= 15e-9,
+            "{} {:?}",
+            i + 1,
+            inverse
+        );
+        let m12 = inverse.reduced_length.abs();
+        for error in [
+            azimuth_error(inverse.initial_azimuth, azi1),
+            azimuth_error(inverse.final_azimuth, azi2),
+        ] {
+            assert!(error * m12 <= 15e-9, "{} {:?}", i + 1, inverse);
+            assert!(
+                m12 < 100e3 || error <= 1e-12_f64.to_radians(),
+                "{} {:?}",
+                i + 1,
+                inverse
+            );
+        }
+
+        let direct = geodesic.direct(&raw, azi1, s12);
+        let error = geodesic.inverse(&direct.coord, &target).distance;
+        assert!(error <= 15e-9, "{} {:?} {}", i + 1, direct, error);
+        if let Some(m12) = row.get(8) {
+            assert!(
+                (direct.reduced_length - m12).abs() <= 15e-9,
+                "{} {:?}",
+                i + 1,
+                direct
+            );
+        }
+        let error = azimuth_error(direct.final_azimuth, azi2);
+        let radius = Ellipsoid::WGS84.a * lat2.to_radians().cos();
+        assert!(error * radius <= 15e-9, "{} {:?}", i + 1, direct);
+        assert!(
+            lat2.abs() > 89.9 || error <= 1e-12_f64.to_radians(),
+            "{} {:?}",
+            i + 1,
+            direct
+        );
+    }
+}
+
+#[test]
+fn test_geodtest() {
+    check_geodtest(GEODTEST);
+}
+
+#[test]
+fn test_geodtest_generated() {
+    check_geodtest(GEODTEST_GENERATED);
+}
+
+// GeographicLib的GeodSolve回归测试中WGS-84的部分，覆盖短距离、近对跖、极点和赤道，
+// 参考值保留的位数与原测试一致
+#[test]
+#[allow(clippy::excessive_precision)]
+fn test_geodsolve() {
+    let geodesic = Geodesic::default();
+    let inverse = |lat1, lon1, lat2, lon2| geodesic.inverse(&coord(lat1, lon1), &coord(lat2, lon2));
+
+    let result = inverse(40.6, -73.8, 49.01666667, 2.55);
+    assert!((result.initial_azimuth - 53.47022).abs() < 0.5e-5);
+    assert!((result.final_azimuth - 111.59367).abs() < 0.5e-5);
+    assert!((result.distance - 5853226.0).abs() < 0.5);
+
+    let result = geodesic.direct(&coord(40.63972222, -73.77888889), 53.5, 5850e3);
+    assert!((result.coord.lat - 49.01467).abs() < 0.5e-5);
+    assert!((result.coord.lon - 2.56106).abs() < 0.5e-5);
+    assert!((result.final_azimuth - 111.62947).abs() < 0.5e-5);
+
+    // 短距离
+    let result = inverse(36.493349428792, 0.0, 36.49334942879201, 0.0000008);
+    assert!((result.distance - 0.072).abs() < 0.5e-3);
+    let result = inverse(54.1589, 15.3872, 54.1591, 15.3877);
+    assert!((result.initial_azimuth - 55.723110355).abs() < 5e-9);
+    assert!((result.final_azimuth - 55.723515675).abs() < 5e-9);
+    assert!((result.distance - 39.527686385).abs() < 5e-9);
+    assert!((result.reduced_length - 39.527686385).abs() < 5e-9);
+
+    // 终点为极点
+    let result = geodesic.direct(&coord(0.01777745589997, 30.0), 0.0, 10e6);
+    assert!((result.coord.lat - 90.0).abs() < 0.5e-5);
+
+    // 近对跖
+    for (lat1, lat2, lon2, distance) in [
+        (
+            88.202499451857,
+            -88.202499451857,
+            179.981022032992859592,
+            20003898.214,
+        ),
+        (
+            89.333123580033,
+            -89.333123580032997687,
+            179.99295812360148422,
+            20003926.881,
+        ),
+        (
+            56.320923501171,
+            -56.320923501171,
+            179.664747671772880215,
+            19993558.287,
+        ),
+        (
+            52.784459512564,
+            -52.784459512563990912,
+            179.634407464943777557,
+            19991596.095,
+        ),
+        (
+            48.522876735459,
+            -48.52287673545898293,
+            179.599720456223079643,
+            19989144.774,
+        ),
+    ] {
+        let result = inverse(lat1, 0.0, lat2, lon2);
+        assert!((result.distance - distance).abs() < 0.5e-3, "{:?}", result);
+    }
+    let result = inverse(
+        -(41.0 + 19.0 / 60.0),
+        174.0 + 49.0 / 60.0,
+        40.0 + 58.0 / 60.0,
+        -5.5,
+    );
+    assert!((result.initial_azimuth - 160.39137649664).abs() < 0.5e-11);
+    assert!((result.final_azimuth - 19.50042925176).abs() < 0.5e-11);
+    assert!((result.distance - 19960543.857179).abs() < 0.5e-6);
+    let result = inverse(27.2, 0.0, -27.1, 179.5);
+    assert!((result.initial_azimuth - 45.82468716758).abs() < 0.5e-11);
+    assert!((result.final_azimuth - 134.22776532670).abs() < 0.5e-11);
+    assert!((result.distance - 19974354.765767).abs() < 0.5e-6);
+    let result = inverse(5.0, 0.00000000000001, 10.0, 180.0);
+    assert!((result.initial_azimuth - 0.000000000000035).abs() < 1.5e-14);
+    assert!((result.final_azimuth - 179.99999999999996).abs() < 1.5e-14);
+    assert!((result.distance - 18345191.174332713).abs() < 5e-9);
+
+    // 赤道
+    for (lon2, azi1, azi2, distance) in [
+        (179.0, 90.0, 90.0, 19926189.0),
+        (179.5, 55.96650, 124.03350, 19980862.0),
+        (180.0, 0.0, 180.0, 20003931.0),
+    ] {
+        let result = inverse(0.0, 0.0, 0.0, lon2);
+        assert!(
+            (result.initial_azimuth - azi1).abs() < 0.5e-5,
+            "{:?}",
+            result
+        );
+        assert!(
+            (result.final_azimuth.abs() - azi2).abs() < 0.5e-5,
+            "{:?}",
+            result
+        );
+        assert!((result.distance - distance).abs() < 0.5, "{:?}", result);
+    }
+    let result = inverse(0.0, 0.0, 1.0, 180.0);
+    assert!(result.initial_azimuth.abs() < 0.5e-5);
+    assert!((result.final_azimuth.abs() - 180.0).abs() < 0.5e-5);
+    assert!((result.distance - 19893357.0).abs() < 0.5);
+
+    // 极小的负方位角向西
+    let result = geodesic.direct(&coord(45.0, 0.0), -0.000000000000000003, 1e7);
+    assert!((result.coord.lat - 45.30632).abs() < 0.5e-5);
+    assert!((result.coord.lon.abs() - 180.0).abs() < 0.5e-5);
+    assert!((result.final_azimuth.abs() - 180.0).abs() < 0.5e-5);
+}
@@ -15,15 +15,15 @@ lazy_static! {
 
 #[test]
 fn test1() {
-    assert_eq!(get_angle(&RAW, &TARGET, 0.0), 314.9431579874174);
+    assert_eq!(get_angle(&RAW, &TARGET, 0.0), 315.627125823737);
 }
 
 #[test]
 fn test2() {
-    assert_eq!(get_angle(&TARGET, &RAW, 0.0), 135.4793355143595);
+    assert_eq!(get_angle(&TARGET, &RAW, 0.0), 134.5624631339075);
 }
 
 #[test]
 fn test3() {
-    assert_eq!(get_angle(&TARGET, &RAW, 15.0), 120.47933551435949);
+    assert_eq!(get_angle(&TARGET, &RAW, 15.0), 119.5624631339075);
 }
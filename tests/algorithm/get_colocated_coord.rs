@@ -1,5 +1,8 @@
 use lazy_static::lazy_static;
-use navdata_components::algorithm::position_transformation::get_colocated_coord;
+use navdata_components::algorithm::bearing::{MagneticBearing, TrueBearing};
+use navdata_components::algorithm::position_transformation::{
+    get_colocated_coord, get_colocated_coord_true,
+};
 use navdata_components::coordinate::Coordinate;
 
 lazy_static! {
@@ -12,20 +15,20 @@ lazy_static! {
 #[test]
 fn test1() {
     assert_eq!(
-        get_colocated_coord(&RAW, 112.0, 36502.0, 0.0),
+        get_colocated_coord_true(&RAW, TrueBearing::new(112.0), 36502.0),
         Coordinate {
-            lat: 39.87617306290192,
-            lon: 116.39561635066224
+            lat: 39.87617306292286,
+            lon: 116.39561635059754
         }
     );
 }
 #[test]
 fn test2() {
     assert_eq!(
-        get_colocated_coord(&RAW, 232.0, 2354.5, 0.0),
+        get_colocated_coord_true(&RAW, TrueBearing::new(232.0), 2354.5),
         Coordinate {
-            lat: 39.986942788836465,
-            lon: 115.97827691463395
+            lat: 39.98694278883693,
+            lon: 115.97827691463466
         }
     );
 }
@@ -33,10 +36,22 @@ fn test2() {
 #[test]
 fn test3() {
     assert_eq!(
-        get_colocated_coord(&RAW, 232.0, 2354.5, 12.0),
+        get_colocated_coord(&RAW, MagneticBearing::new(232.0), 2354.5, 12.0),
         Coordinate {
-            lat: 39.99070164156582,
-            lon: 115.97522159435215
+            lat: 39.990701641567036,
+            lon: 115.97522159435529
         }
     );
 }
+
+#[test]
+fn test_antimeridian() {
+    let raw = Coordinate {
+        lat: 0.0,
+        lon: 179.9,
+    };
+    let result = get_colocated_coord_true(&raw, TrueBearing::new(90.0), 50000.0);
+
+    assert!(result.lon < -179.0 && result.lon >= -180.0);
+    assert_eq!(result, result.normalize().unwrap());
+}
@@ -15,10 +15,10 @@ lazy_static! {
 
 #[test]
 fn test1() {
-    assert_eq!(get_distance(&RAW, &TARGET), 195964.51475056555);
+    assert_eq!(get_distance(&RAW, &TARGET), 195964.51475248535);
 }
 
 #[test]
 fn test2() {
-    assert_eq!(get_distance(&TARGET, &RAW), 195964.5147505656);
+    assert_eq!(get_distance(&TARGET, &RAW), 195964.51475248535);
 }
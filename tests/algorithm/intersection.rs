@@ -0,0 +1,216 @@
+use navdata_components::algorithm::bearing::{MagneticBearing, TrueBearing};
+use navdata_components::algorithm::geodesic::Geodesic;
+use navdata_components::algorithm::intersection::{
+    get_dme_dme_intersection, get_dme_position, get_radial_dme_intersection,
+    get_radial_dme_intersection_true, get_radial_intersection, get_radial_intersection_true,
+    get_radial_intersection_true_with, get_segment_intersection,
+};
+use navdata_components::algorithm::measurement::{get_distance, get_initial_bearing};
+use navdata_components::coordinate::Coordinate;
+use navdata_components::Ellipsoid;
+
+fn coord(lat: f64, lon: f64) -> Coordinate {
+    Coordinate { lat, lon }
+}
+
+/// 从`fix`沿`azimuth`方向`distance`米处的台站，以及台站指向`fix`的真方向
+fn station(fix: &Coordinate, azimuth: f64, distance: f64) -> (Coordinate, TrueBearing) {
+    let station = Geodesic::default().direct(fix, azimuth, distance).coord;
+    let bearing = get_initial_bearing(&station, fix);
+    (station, bearing)
+}
+
+#[test]
+fn test_radial_intersection() {
+    let fix = coord(40.2, 116.9);
+    for (azimuth1, distance1, azimuth2, distance2) in [
+        (200.0, 50_000.0, 300.0, 30_000.0),
+        (10.0, 120_000.0, 95.0, 8_000.0),
+        (170.0, 900_000.0, 260.0, 2_000_000.0),
+    ] {
+        let (raw1, bearing1) = station(&fix, azimuth1, distance1);
+        let (raw2, bearing2) = station(&fix, azimuth2, distance2);
+        let result = get_radial_intersection_true(&raw1, bearing1, &raw2, bearing2).unwrap();
+        assert!(get_distance(&result, &fix) < 1e-6, "{:?}", result);
+    }
+}
+
+#[test]
+fn test_radial_intersection_magnetic() {
+    let fix = coord(39.5, 116.2);
+    let (raw1, bearing1) = station(&fix, 45.0, 40_000.0);
+    let (raw2, bearing2) = station(&fix, 315.0, 25_000.0);
+    let (mag_var1, mag_var2) = (-7.0, -6.5);
+    let result = get_radial_intersection(
+        &raw1,
+        bearing1.to_magnetic(mag_var1),
+        mag_var1,
+        &raw2,
+        bearing2.to_magnetic(mag_var2),
+        mag_var2,
+    )
+    .unwrap();
+    assert!(get_distance(&result, &fix) < 1e-6);
+}
+
+#[test]
+fn test_radial_intersection_none() {
+    let raw1 = coord(40.0, 116.0);
+    let raw2 = coord(40.0, 117.0);
+    // 交点在第二条射线后方
+    assert_eq!(
+        get_radial_intersection_true(
+            &raw1,
+            TrueBearing::new(45.0),
+            &raw2,
+            TrueBearing::new(45.0 + 180.0 - 60.0)
+        ),
+        None
+    );
+    // 同一条测地线
+    let bearing = get_initial_bearing(&raw1, &raw2);
+    let reverse = get_initial_bearing(&raw2, &raw1);
+    assert_eq!(
+        get_radial_intersection_true(&raw1, bearing, &raw2, reverse),
+        None
+    );
+}
+
+#[test]
+fn test_radial_intersection_sphere() {
+    // 赤道和经线的交点
+    let result = get_radial_intersection_true_with(
+        &coord(0.0, 10.0),
+        TrueBearing::new(90.0),
+        &coord(30.0, 20.0),
+        TrueBearing::new(180.0),
+        &Ellipsoid::SPHERE,
+    )
+    .unwrap();
+    assert!(result.lat.abs() < 1e-9);
+    assert!((result.lon - 20.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_segment_intersection() {
+    let result = get_segment_intersection(
+        &coord(39.0, 116.0),
+        &coord(41.0, 118.0),
+        &coord(41.0, 116.0),
+        &coord(39.0, 118.0),
+    )
+    .unwrap();
+    let geodesic = Geodesic::default();
+    let check = |a: Coordinate, b: Coordinate| {
+        let leg = geodesic.inverse(&a, &b);
+        let part = geodesic.inverse(&a, &result);
+        assert!((part.initial_azimuth - leg.initial_azimuth).abs() < 1e-9);
+        assert!(part.distance < leg.distance);
+    };
+    check(coord(39.0, 116.0), coord(41.0, 118.0));
+    check(coord(41.0, 116.0), coord(39.0, 118.0));
+
+    // 延长线相交但航段不相交
+    assert_eq!(
+        get_segment_intersection(
+            &coord(39.0, 116.0),
+            &coord(39.5, 116.5),
+            &coord(41.0, 116.0),
+            &coord(39.0, 118.0),
+        ),
+        None
+    );
+
+    // 共同端点
+    let result = get_segment_intersection(
+        &coord(39.0, 116.0),
+        &coord(40.0, 117.0),
+        &coord(40.0, 117.0),
+        &coord(41.0, 116.0),
+    )
+    .unwrap();
+    assert!(get_distance(&result, &coord(40.0, 117.0)) < 1e-6);
+}
+
+#[test]
+fn test_radial_dme_intersection() {
+    let vor = coord(40.08, 116.6);
+    let dme = coord(39.8, 116.1);
+    let radius = 15.0 * 1852.0;
+    let mag_var = -7.0;
+    let result =
+        get_radial_dme_intersection(&vor, MagneticBearing::new(250.0), mag_var, &dme, radius);
+    assert_eq!(result.len(), 2);
+    for point in &result {
+        assert!((get_distance(&dme, point) - radius).abs() < 1e-6);
+        let azimuth = get_initial_bearing(&vor, point);
+        assert!((azimuth.degrees() - (250.0 + mag_var)).abs() < 1e-9);
+    }
+    assert!(get_distance(&vor, &result[0]) < get_distance(&vor, &result[1]));
+
+    // 射线起点在圆内时只有前方一个交点
+    let result = get_radial_dme_intersection_true(&dme, TrueBearing::new(90.0), &dme, radius);
+    assert_eq!(result.len(), 1);
+    assert!((get_distance(&dme, &result[0]) - radius).abs() < 1e-6);
+
+    // 射线离圆太远
+    assert!(get_radial_dme_intersection_true(&vor, TrueBearing::new(0.0), &dme, radius).is_empty());
+}
+
+#[test]
+fn test_dme_dme_intersection() {
+    let center1 = coord(40.08, 116.6);
+    let center2 = coord(39.5, 116.9);
+    let (radius1, radius2) = (50_000.0, 40_000.0);
+    let result = get_dme_dme_intersection(&center1, radius1, &center2, radius2);
+    assert_eq!(result.len(), 2);
+    for point in &result {
+        assert!((get_distance(&center1, point) - radius1).abs() < 1e-6);
+        assert!((get_distance(&center2, point) - radius2).abs() < 1e-6);
+    }
+    // 第一个点在第一个圆心看向第二个圆心的右侧
+    let bearing = get_initial_bearing(&center1, &center2);
+    let right = get_initial_bearing(&center1, &result[0]) - bearing;
+    assert!(right.to_radians().sin() > 0.0);
+
+    let distance = get_distance(&center1, &center2);
+    assert!(get_dme_dme_intersection(&center1, 1000.0, &center2, 1000.0).is_empty());
+    assert!(get_dme_dme_intersection(&center1, distance + 1e5, &center2, 1000.0).is_empty());
+    assert!(get_dme_dme_intersection(&center1, 1000.0, &center1, 1000.0).is_empty());
+}
+
+#[test]
+fn test_dme_position() {
+    let aircraft = coord(39.9, 116.4);
+    let stations = [
+        coord(40.08, 116.6),
+        coord(39.5, 116.9),
+        coord(39.7, 115.8),
+        coord(40.4, 116.0),
+    ];
+    let ranges: Vec<_> = stations
+        .iter()
+        .map(|station| (*station, get_distance(station, &aircraft)))
+        .collect();
+    let fix = get_dme_position(&ranges).unwrap();
+    assert!(get_distance(&fix.coord, &aircraft) < 1e-6);
+    assert!(fix.rms_residual < 1e-6);
+    assert_eq!(fix.residuals.len(), 4);
+    assert!(fix.dilution > 0.5 && fix.dilution < 2.0);
+
+    // 测距有误差时残差反映不一致程度
+    let mut noisy = ranges.clone();
+    noisy[0].1 += 100.0;
+    noisy[2].1 -= 100.0;
+    let fix = get_dme_position(&noisy).unwrap();
+    assert!(fix.rms_residual > 1.0 && fix.rms_residual < 100.0);
+    assert!(get_distance(&fix.coord, &aircraft) < 200.0);
+
+    assert!(get_dme_position(&ranges[..2]).is_none());
+
+    // 前两台的距离都为0时没有初始值
+    let mut zero = ranges.clone();
+    zero[0].1 = 0.0;
+    zero[1].1 = 0.0;
+    assert!(get_dme_position(&zero).is_none());
+}
@@ -1,4 +1,11 @@
+mod bearing;
+mod densify;
+mod ellipsoid;
+mod geodesic;
 mod get_angle;
 mod get_circle_distance;
 mod get_colocated_coord;
 mod get_distance;
+mod intersection;
+mod rhumb;
+mod track;
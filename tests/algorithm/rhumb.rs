@@ -0,0 +1,94 @@
+use navdata_components::algorithm::bearing::TrueBearing;
+use navdata_components::algorithm::measurement::{get_rhumb_bearing, get_rhumb_distance};
+use navdata_components::algorithm::position_transformation::get_rhumb_colocated_coord_true;
+use navdata_components::algorithm::rhumb::Rhumb;
+use navdata_components::coordinate::Coordinate;
+
+fn coord(lat: f64, lon: f64) -> Coordinate {
+    Coordinate { lat, lon }
+}
+
+// 参考值来自GeographicLib
+#[test]
+fn test_inverse() {
+    let raw = coord(40.6, -73.8);
+    let target = coord(51.6, -0.5);
+    assert!((get_rhumb_distance(&raw, &target) - 5771083.38333).abs() < 1e-4);
+    assert!((get_rhumb_bearing(&raw, &target, 0.0).degrees() - 77.76838971).abs() < 1e-8);
+    assert!((get_rhumb_bearing(&target, &raw, 0.0).degrees() - 257.76838971).abs() < 1e-8);
+    // 磁航向
+    assert!((get_rhumb_bearing(&raw, &target, -13.0).degrees() - 90.76838971).abs() < 1e-8);
+}
+
+#[test]
+fn test_direct() {
+    let result = get_rhumb_colocated_coord_true(&coord(40.6, -73.8), TrueBearing::new(51.0), 5.5e6);
+    assert!((result.lat - 71.688899882813).abs() < 1e-9);
+    assert!((result.lon - 0.255519824749).abs() < 1e-9);
+}
+
+#[test]
+fn test_east_west() {
+    let rhumb = Rhumb::default();
+    let raw = coord(45.0, 170.0);
+    let target = coord(45.0, -170.0);
+    let result = rhumb.inverse(&raw, &target);
+    assert_eq!(result.bearing, 90.0);
+    // 沿纬线圈，跨越180°经线
+    assert!((result.distance - 1576936.701879562).abs() < 1e-6);
+
+    let result = rhumb.direct(&raw, 90.0, result.distance);
+    assert!((result.lat - 45.0).abs() < 1e-12);
+    assert!((result.lon + 170.0).abs() < 1e-9);
+
+    let result = rhumb.inverse(&target, &raw);
+    assert_eq!(result.bearing, 270.0);
+}
+
+#[test]
+fn test_poles() {
+    let rhumb = Rhumb::default();
+    // 赤道到极点为四分之一子午线
+    let result = rhumb.inverse(&coord(0.0, 30.0), &coord(90.0, 0.0));
+    assert!((result.distance - 10001965.729313).abs() < 1e-5);
+    assert_eq!(result.bearing, 0.0);
+    assert_eq!(
+        rhumb.inverse(&coord(-90.0, 0.0), &coord(10.0, 0.0)).bearing,
+        0.0
+    );
+    assert_eq!(
+        rhumb.inverse(&coord(10.0, 0.0), &coord(-90.0, 0.0)).bearing,
+        180.0
+    );
+
+    // 越过极点时停在极点
+    let result = rhumb.direct(&coord(80.0, 10.0), 45.0, 5e6);
+    assert_eq!(result, coord(90.0, 10.0));
+    let result = rhumb.direct(&coord(-80.0, 10.0), 200.0, 5e6);
+    assert_eq!(result.lat, -90.0);
+    // 停在极点时经度同样规范化
+    assert_eq!(
+        rhumb.direct(&coord(80.0, 180.0), 0.0, 5e6),
+        coord(90.0, -180.0)
+    );
+    assert_eq!(
+        get_rhumb_colocated_coord_true(&coord(-80.0, 540.0), TrueBearing::new(180.0), 5e6),
+        coord(-90.0, -180.0)
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let rhumb = Rhumb::default();
+    let raw = coord(35.0, 100.0);
+    for lat in (-85..=85).step_by(17) {
+        for lon in (-180..180).step_by(25) {
+            let target = coord(lat as f64, lon as f64);
+            let inverse = rhumb.inverse(&raw, &target);
+            let result = rhumb.direct(&raw, inverse.bearing, inverse.distance);
+            assert!((result.lat - target.lat).abs() < 1e-9, "{:?}", target);
+            let dlon = (result.lon - target.lon + 540.0) % 360.0 - 180.0;
+            assert!(dlon.abs() < 1e-9, "{:?}", target);
+        }
+    }
+}
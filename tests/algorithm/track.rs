@@ -0,0 +1,100 @@
+use navdata_components::algorithm::geodesic::Geodesic;
+use navdata_components::algorithm::measurement::{
+    get_along_track_distance, get_along_track_distance_with, get_closest_coord_on_segment,
+    get_cross_track_distance, get_cross_track_distance_with, get_distance,
+};
+use navdata_components::coordinate::Coordinate;
+use navdata_components::Ellipsoid;
+
+fn coord(lat: f64, lon: f64) -> Coordinate {
+    Coordinate { lat, lon }
+}
+
+/// 在航段上距起点`along`米处向右垂直偏移`cross`米的点
+fn offset(raw: &Coordinate, target: &Coordinate, along: f64, cross: f64) -> Coordinate {
+    let geodesic = Geodesic::default();
+    let azimuth = geodesic.inverse(raw, target).initial_azimuth;
+    let foot = geodesic.direct(raw, azimuth, along);
+    geodesic
+        .direct(&foot.coord, foot.final_azimuth + 90.0, cross)
+        .coord
+}
+
+#[test]
+fn test_cross_and_along_track() {
+    let raw = coord(40.07, 116.6);
+    let target = coord(31.14, 121.8);
+    for (along, cross) in [
+        (0.0, 5000.0),
+        (350_000.0, 18_520.0),
+        (600_000.0, -92_600.0),
+        (-150_000.0, 40_000.0),
+        (1_400_000.0, -1_000_000.0),
+    ] {
+        let point = offset(&raw, &target, along, cross);
+        let result = get_cross_track_distance(&point, &raw, &target);
+        assert!((result - cross).abs() < 1e-6, "{} {}", cross, result);
+        let result = get_along_track_distance(&point, &raw, &target);
+        assert!((result - along).abs() < 1e-6, "{} {}", along, result);
+    }
+}
+
+#[test]
+fn test_point_on_track() {
+    let raw = coord(51.47, -0.45);
+    let target = coord(40.64, -73.78);
+    let point = offset(&raw, &target, 2_000_000.0, 0.0);
+    assert!(get_cross_track_distance(&point, &raw, &target).abs() < 1e-6);
+    assert!((get_along_track_distance(&point, &raw, &target) - 2_000_000.0).abs() < 1e-6);
+    assert!(
+        (get_along_track_distance(&target, &raw, &target) - get_distance(&raw, &target)).abs()
+            < 1e-6
+    );
+}
+
+#[test]
+fn test_sphere() {
+    // 与球面公式比较
+    let sphere = Ellipsoid::SPHERE;
+    let radius = sphere.a;
+    let raw = coord(53.3206, -1.7297);
+    let target = coord(53.1887, 0.1334);
+    let point = coord(53.2611, -0.7972);
+
+    let geodesic = Geodesic::new(&sphere);
+    let d13 = geodesic.inverse(&raw, &point).distance / radius;
+    let theta13 = geodesic.inverse(&raw, &point).initial_azimuth.to_radians();
+    let theta12 = geodesic.inverse(&raw, &target).initial_azimuth.to_radians();
+    let cross = (d13.sin() * (theta13 - theta12).sin()).asin();
+    let along = (d13.cos() / cross.cos()).acos();
+
+    let result = get_cross_track_distance_with(&point, &raw, &target, &sphere);
+    assert!((result - cross * radius).abs() < 1e-6);
+    // 点在航向左侧
+    assert!(result < 0.0);
+    let result = get_along_track_distance_with(&point, &raw, &target, &sphere);
+    assert!((result - along * radius).abs() < 1e-6);
+}
+
+#[test]
+fn test_closest_coord_on_segment() {
+    let raw = coord(40.07, 116.6);
+    let target = coord(31.14, 121.8);
+
+    let point = offset(&raw, &target, 300_000.0, 20_000.0);
+    let closest = get_closest_coord_on_segment(&point, &raw, &target);
+    assert!((get_distance(&raw, &closest) - 300_000.0).abs() < 1e-6);
+    assert!((get_distance(&point, &closest) - 20_000.0).abs() < 1e-6);
+
+    let point = offset(&raw, &target, -50_000.0, 20_000.0);
+    assert_eq!(get_closest_coord_on_segment(&point, &raw, &target), raw);
+
+    let point = offset(&raw, &target, 2_000_000.0, -20_000.0);
+    assert_eq!(get_closest_coord_on_segment(&point, &raw, &target), target);
+
+    // 航段退化为一点
+    assert_eq!(get_closest_coord_on_segment(&point, &raw, &raw), raw);
+    assert!(
+        (get_cross_track_distance(&point, &raw, &raw) - get_distance(&point, &raw)).abs() < 1e-9
+    );
+}
@@ -0,0 +1,133 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{
+    AxisOrder, CoordFormat, CoordParseErrorKind, Coordinate, ParseOptions,
+};
+
+fn parse(s: &str, axis_order: AxisOrder) -> Result<Coordinate, CoordParseErrorKind> {
+    Coordinate::parse_with(
+        s,
+        &ParseOptions {
+            axis_order,
+            ..Default::default()
+        },
+    )
+    .map_err(|e| e.kind)
+}
+
+const EXPECTED: Coordinate = Coordinate {
+    lat: 40.5,
+    lon: 116.5,
+};
+
+#[test]
+fn test_hemisphere_decimal() {
+    assert_eq!(Coordinate::from_str("N40.5 E116.5"), Ok(EXPECTED));
+    assert_eq!(
+        Coordinate::parse_as("S5.25 W7.5", CoordFormat::HemisphereDecimal),
+        Ok(Coordinate {
+            lat: -5.25,
+            lon: -7.5
+        })
+    );
+    // 纬度的整数部分最多2位，不检查范围时也不会得到403.5
+    assert_eq!(
+        Coordinate::from_str("N403.5E116.5").map_err(|e| e.kind),
+        Err(CoordParseErrorKind::LatParseError)
+    );
+    assert_eq!(
+        Coordinate::from_str("S05.25 W007.5"),
+        Ok(Coordinate {
+            lat: -5.25,
+            lon: -7.5
+        })
+    );
+}
+
+#[test]
+fn test_lon_first_with_hemisphere() {
+    assert_eq!(parse("E116.5 N40.5", AxisOrder::Auto), Ok(EXPECTED));
+    assert_eq!(parse("E116.5N40.5", AxisOrder::Auto), Ok(EXPECTED));
+    assert_eq!(parse("E11630.0 N4030.0", AxisOrder::Auto), Ok(EXPECTED));
+    assert_eq!(parse("11630E 4030N", AxisOrder::Auto), Ok(EXPECTED));
+    assert_eq!(
+        parse("东经116°30′ 北纬40°30′", AxisOrder::Auto),
+        Ok(EXPECTED)
+    );
+    assert_eq!(parse("E116.5 N40.5", AxisOrder::LonLat), Ok(EXPECTED));
+}
+
+#[test]
+fn test_lat_first_auto() {
+    assert_eq!(parse("N40.5 E116.5", AxisOrder::Auto), Ok(EXPECTED));
+    assert_eq!(parse("N4030.0E11630.0", AxisOrder::Auto), Ok(EXPECTED));
+    assert_eq!(parse("40.5,116.5", AxisOrder::Auto), Ok(EXPECTED));
+}
+
+#[test]
+fn test_geojson() {
+    assert_eq!(parse("116.5,40.5", AxisOrder::LonLat), Ok(EXPECTED));
+    assert_eq!(parse("116.5,40.5", AxisOrder::Auto), Ok(EXPECTED));
+    assert_eq!(
+        parse("-73.5,40.5", AxisOrder::LonLat),
+        Ok(Coordinate {
+            lat: 40.5,
+            lon: -73.5
+        })
+    );
+}
+
+#[test]
+fn test_ambiguous() {
+    assert_eq!(
+        parse("40.5,50.5", AxisOrder::Auto),
+        Err(CoordParseErrorKind::AmbiguousAxisOrder)
+    );
+    assert_eq!(
+        parse("116.5,120.5", AxisOrder::Auto),
+        Err(CoordParseErrorKind::AmbiguousAxisOrder)
+    );
+    let err = Coordinate::parse_with(
+        "40.5,50.5",
+        &ParseOptions {
+            axis_order: AxisOrder::Auto,
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.span, 0..9);
+    assert!(err.to_string().starts_with("ambiguous axis order"));
+}
+
+#[test]
+fn test_wrong_order() {
+    assert_eq!(
+        parse("40.5,116.5", AxisOrder::LonLat),
+        Err(CoordParseErrorKind::LatOutOfRange)
+    );
+    let err = Coordinate::parse_with(
+        "40.5,116.5",
+        &ParseOptions {
+            axis_order: AxisOrder::LonLat,
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.fragment(), "116.5");
+}
+
+#[test]
+fn test_parse_error_axis() {
+    assert_eq!(
+        parse("E116.5 N40.x", AxisOrder::Auto),
+        Err(CoordParseErrorKind::LatParseError)
+    );
+    assert_eq!(
+        parse("E116.x N40.5", AxisOrder::Auto),
+        Err(CoordParseErrorKind::LonParseError)
+    );
+    assert_eq!(
+        parse("116.x,40.5", AxisOrder::LonLat),
+        Err(CoordParseErrorKind::LonParseError)
+    );
+}
@@ -55,6 +55,7 @@ fn minute() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn second() {
     assert_eq!(C1.lat_s(), 0.0);
     assert_eq!(C2.lat_s(), 0.0);
@@ -70,6 +71,7 @@ fn second() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn second_p() {
     assert_eq!((C1.lat_sp() * 10.0).round() / 10.0, 0.0);
     assert_eq!((C2.lat_sp() * 10.0).round() / 10.0, 0.0);
@@ -85,6 +87,7 @@ fn second_p() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn minute_second() {
     assert_eq!((C1.lat_md() * 100.0).round() / 100.0, 0.0);
     assert_eq!((C2.lat_md() * 100.0).round() / 100.0, 30.0);
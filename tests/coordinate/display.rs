@@ -0,0 +1,146 @@
+use lazy_static::lazy_static;
+use navdata_components::coordinate::{CoordFormat, Coordinate};
+
+lazy_static! {
+    static ref C1: Coordinate = Coordinate {
+        lat: 40.51,
+        lon: 116.51
+    };
+    static ref C2: Coordinate = Coordinate {
+        lat: -5.25,
+        lon: -70.999999999
+    };
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(C1.to_string(), "40.510000,116.510000");
+    assert_eq!(format!("{:.2}", *C1), "40.51,116.51");
+    assert_eq!(C2.to_string(), "-05.250000,-71.000000");
+}
+
+#[test]
+fn test_formats() {
+    let cases = [
+        (CoordFormat::DegreeMinute, "N4030.6000E11630.6000"),
+        (
+            CoordFormat::DegreeMinuteSecondDecimal,
+            "N403036.00E1163036.00",
+        ),
+        (CoordFormat::Arinc424, "N40303600E116303600"),
+        (CoordFormat::DegreeMinuteSecond, "N403036E1163036"),
+        (CoordFormat::EuroScope, "N040.30.36.000 E116.30.36.000"),
+        (CoordFormat::Pmdg, "N 40 30.6000 E 116 30.6000"),
+        (CoordFormat::Decimal, "40.510000,116.510000"),
+        (CoordFormat::Icao, "403036N1163036E"),
+        (CoordFormat::Symbolic, "40°30'36.00\"N 116°30'36.00\"E"),
+    ];
+    for (format, expected) in cases {
+        assert_eq!(C1.display(format).to_string(), expected);
+    }
+}
+
+#[test]
+fn test_precision() {
+    assert_eq!(
+        C1.display(CoordFormat::Pmdg).precision(6).to_string(),
+        "N 40 30.600000 E 116 30.600000"
+    );
+    assert_eq!(
+        format!("{:.1}", C1.display(CoordFormat::DegreeMinute)),
+        "N4030.6E11630.6"
+    );
+    assert_eq!(
+        C1.display(CoordFormat::Decimal).precision(0).to_string(),
+        "41.,117."
+    );
+    assert_eq!(
+        C1.display(CoordFormat::EuroScope).precision(6).to_string(),
+        "N040.30.36.000 E116.30.36.000"
+    );
+}
+
+#[test]
+fn test_negative_and_carry() {
+    let cases = [
+        (CoordFormat::DegreeMinute, "S0515.0000W07100.0000"),
+        (CoordFormat::DegreeMinuteSecond, "S051500W0710000"),
+        (CoordFormat::EuroScope, "S005.15.00.000 W071.00.00.000"),
+        (CoordFormat::Icao, "051500S0710000W"),
+        (CoordFormat::Symbolic, "5°15'00.00\"S 71°00'00.00\"W"),
+    ];
+    for (format, expected) in cases {
+        assert_eq!(C2.display(format).to_string(), expected);
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let coords = [
+        *C1,
+        *C2,
+        Coordinate { lat: 0.0, lon: 0.0 },
+        Coordinate {
+            lat: 89.999999,
+            lon: 179.99999,
+        },
+        Coordinate {
+            lat: -45.123456789,
+            lon: 7.000001,
+        },
+        Coordinate {
+            lat: 39.19340277777777,
+            lon: -116.5736111111111,
+        },
+    ];
+
+    for coord in coords {
+        for format in CoordFormat::ALL {
+            for precision in 0..=6 {
+                let text = coord.display(format).precision(precision).to_string();
+                let parsed = Coordinate::parse_as(&text, format)
+                    .unwrap_or_else(|e| panic!("{:?} {}: {}", format, text, e));
+
+                if matches!(format, CoordFormat::Utm | CoordFormat::Mgrs) {
+                    // 网格坐标的误差以米计，带边缘的坐标再次输出时可能落在相邻的带
+                    let metres = match format {
+                        CoordFormat::Utm => 10f64.powi(-(precision as i32)),
+                        _ => 10f64.powi(5 - precision.min(5) as i32),
+                    };
+                    let dlon = (parsed.lon - coord.lon + 540.0) % 360.0 - 180.0;
+                    let dx = dlon * 111_320.0 * coord.lat.to_radians().cos();
+                    let dy = (parsed.lat - coord.lat) * 111_320.0;
+                    assert!(dx.hypot(dy) <= metres, "{}", text);
+                    continue;
+                }
+
+                let unit = match format {
+                    CoordFormat::Decimal | CoordFormat::HemisphereDecimal => 1.0,
+                    CoordFormat::DegreeMinute | CoordFormat::Pmdg => 1.0 / 60.0,
+                    _ => 1.0 / 3600.0,
+                } * 10f64.powi(-(precision as i32));
+                let unit = match format {
+                    CoordFormat::Arinc424 => 0.01 / 3600.0,
+                    CoordFormat::EuroScope => 0.001 / 3600.0,
+                    CoordFormat::DegreeMinuteSecond => 1.0 / 3600.0,
+                    _ => unit,
+                };
+                assert!(
+                    (parsed.lat - coord.lat).abs() <= unit / 2.0 + 1e-12,
+                    "{}",
+                    text
+                );
+                assert!(
+                    (parsed.lon - coord.lon).abs() <= unit / 2.0 + 1e-12,
+                    "{}",
+                    text
+                );
+
+                assert_eq!(
+                    parsed.display(format).precision(precision).to_string(),
+                    text
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use navdata_components::coordinate::{CoordRangeError, Coordinate, Dms, DmsError, Hemisphere};
+
+lazy_static! {
+    static ref C1: Coordinate = Coordinate {
+        lat: 40.51,
+        lon: 116.51
+    };
+    static ref C2: Coordinate = Coordinate {
+        lat: -40.501,
+        lon: -116.999999999
+    };
+}
+
+#[test]
+fn test_lat_dms() {
+    let dms = C1.lat_dms();
+    assert_eq!(dms.hemisphere, Hemisphere::N);
+    assert_eq!(dms.degrees, 40);
+    assert_eq!(dms.minutes, 30);
+    assert!((dms.seconds - 36.0).abs() < 1e-9);
+
+    let dms = C2.lat_dms();
+    assert_eq!(dms.hemisphere, Hemisphere::S);
+    assert_eq!(dms.degrees, 40);
+    assert_eq!(dms.minutes, 30);
+    assert!((dms.seconds - 3.6).abs() < 1e-9);
+}
+
+#[test]
+fn test_lon_dms() {
+    let dms = C1.lon_dms();
+    assert_eq!(dms.hemisphere, Hemisphere::E);
+    assert_eq!(dms.degrees, 116);
+    assert_eq!(dms.minutes, 30);
+
+    let dms = C2.lon_dms();
+    assert_eq!(dms.hemisphere, Hemisphere::W);
+    assert_eq!(dms.degrees, 116);
+    assert_eq!(dms.minutes, 59);
+    assert!(dms.seconds > 59.99);
+}
+
+#[test]
+fn test_round_carry() {
+    let dms = C2.lon_dms().round(2);
+    assert_eq!(
+        dms,
+        Dms {
+            hemisphere: Hemisphere::W,
+            degrees: 117,
+            minutes: 0,
+            seconds: 0.0
+        }
+    );
+
+    let dms = Dms {
+        hemisphere: Hemisphere::N,
+        degrees: 40,
+        minutes: 30,
+        seconds: 59.96,
+    }
+    .round(1);
+    assert_eq!(dms.minutes, 31);
+    assert_eq!(dms.seconds, 0.0);
+
+    let dms = C2.lat_dms().round(1);
+    assert_eq!(dms.seconds, 3.6);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(C1.lat_dms().to_string(), "40°30'36\"N");
+    assert_eq!(format!("{:.2}", C2.lat_dms()), "40°30'03.60\"S");
+    assert_eq!(format!("{:.1}", C2.lon_dms()), "117°00'00.0\"W");
+}
+
+#[test]
+fn test_from_dms() {
+    let lat = Dms {
+        hemisphere: Hemisphere::N,
+        degrees: 40,
+        minutes: 30,
+        seconds: 36.0,
+    };
+    let lon = Dms {
+        hemisphere: Hemisphere::E,
+        degrees: 116,
+        minutes: 30,
+        seconds: 36.0,
+    };
+
+    assert_eq!(
+        Coordinate::from_dms(lat, lon),
+        Ok(Coordinate::from_str("N403036E1163036").unwrap())
+    );
+    assert_eq!(
+        Coordinate::from_dms(lon, lat),
+        Err(DmsError::WrongHemisphere(Hemisphere::E))
+    );
+    assert_eq!(
+        Coordinate::from_dms(Dms { minutes: 60, ..lat }, lon),
+        Err(DmsError::MinutesOutOfRange(60))
+    );
+    assert_eq!(
+        Coordinate::from_dms(
+            lat,
+            Dms {
+                seconds: 60.0,
+                ..lon
+            }
+        ),
+        Err(DmsError::SecondsOutOfRange(60.0))
+    );
+    assert_eq!(
+        Coordinate::from_dms(Dms { degrees: 91, ..lat }, lon),
+        Err(DmsError::OutOfRange(CoordRangeError::LatOutOfRange(91.51)))
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    for coord in [*C1, *C2] {
+        let result = Coordinate::from_dms(coord.lat_dms(), coord.lon_dms()).unwrap();
+        assert!((result.lat - coord.lat).abs() < 1e-12);
+        assert!((result.lon - coord.lon).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{
+    detect_format, CoordFormat, CoordParseErrorKind, Coordinate, ParseOptions,
+};
+
+#[test]
+fn test_parse_as() {
+    let result = Coordinate::parse_as("N403036E1163036", CoordFormat::DegreeMinuteSecond).unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_parse_as_mismatch() {
+    let err = Coordinate::parse_as("N403036E1163036", CoordFormat::Arinc424).unwrap_err();
+
+    assert_eq!(err.kind, CoordParseErrorKind::LatParseError);
+    assert_eq!(err.expected, vec![CoordFormat::Arinc424]);
+    assert_eq!(err.closest, Some(CoordFormat::Arinc424));
+}
+
+#[test]
+fn test_parse_as_mixed() {
+    assert!(Coordinate::from_str("N4030.6,116.51").is_ok());
+    assert_eq!(
+        Coordinate::parse_as("N4030.6,116.51", CoordFormat::DegreeMinute)
+            .unwrap_err()
+            .kind,
+        CoordParseErrorKind::LonParseError
+    );
+}
+
+#[test]
+fn test_parse_with_format() {
+    let options = ParseOptions {
+        format: Some(CoordFormat::Icao),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        Coordinate::parse_with("4030N11630E", &options),
+        Ok(Coordinate {
+            lat: 40.5,
+            lon: 116.5
+        })
+    );
+    assert!(Coordinate::parse_with("N4030.0E11630.0", &options).is_err());
+}
+
+#[test]
+fn test_detect_format() {
+    assert_eq!(
+        detect_format("N403036E1163036"),
+        vec![CoordFormat::DegreeMinuteSecond]
+    );
+    assert_eq!(
+        detect_format("N39113625E116342500"),
+        vec![CoordFormat::Arinc424]
+    );
+    assert_eq!(
+        detect_format("N040.30.36.000 E116.30.36.000"),
+        vec![CoordFormat::EuroScope]
+    );
+    assert_eq!(
+        detect_format("N 40 30.6 E 116 30.6"),
+        vec![CoordFormat::Pmdg]
+    );
+    assert_eq!(detect_format("40.51,116.51"), vec![CoordFormat::Decimal]);
+    assert_eq!(detect_format("403036N1163036E"), vec![CoordFormat::Icao]);
+    assert_eq!(
+        detect_format("北纬40°30′36″ 东经116°30′36″"),
+        vec![CoordFormat::Symbolic]
+    );
+}
+
+#[test]
+fn test_detect_format_none() {
+    assert!(detect_format("4030 11630").is_empty());
+    assert!(detect_format("N4030.6,116.51").is_empty());
+}
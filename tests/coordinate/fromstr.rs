@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use navdata_components::coordinate::{CoordParseError, Coordinate};
+use navdata_components::coordinate::{CoordParseErrorKind, Coordinate};
 
 #[allow(non_snake_case)]
 #[test]
@@ -207,11 +207,11 @@ fn test_fromstr_arinc424() {
 #[test]
 fn test_lat_error() {
     let result = Coordinate::from_str("40.12l3456,116.654321");
-    assert_eq!(result, Err(CoordParseError::LatParseError));
+    assert_eq!(result.unwrap_err().kind, CoordParseErrorKind::LatParseError);
 }
 
 #[test]
 fn test_lon_error() {
     let result = Coordinate::from_str("40.123456,116.6543l21");
-    assert_eq!(result, Err(CoordParseError::LonParseError));
+    assert_eq!(result.unwrap_err().kind, CoordParseErrorKind::LonParseError);
 }
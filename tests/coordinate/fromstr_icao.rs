@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{CoordParseErrorKind, Coordinate};
+
+#[allow(non_snake_case)]
+#[test]
+fn test_fromstr_xxNxxxE() {
+    let result = Coordinate::from_str("40N116E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.0,
+            lon: 116.0
+        }
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn test_fromstr_xxxxNxxxxxE_1() {
+    let result = Coordinate::from_str("4000N11600E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.0,
+            lon: 116.0
+        }
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn test_fromstr_xxxxNxxxxxE_2() {
+    let result = Coordinate::from_str("4030N11630E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.5,
+            lon: 116.5
+        }
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn test_fromstr_xxxxNxxxxxE_3() {
+    let result = Coordinate::from_str("4030N07830W").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.5,
+            lon: -78.5
+        }
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn test_fromstr_xxxxxxNxxxxxxxE_1() {
+    let result = Coordinate::from_str("400000N1160000E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.0,
+            lon: 116.0
+        }
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn test_fromstr_xxxxxxNxxxxxxxE_2() {
+    let result = Coordinate::from_str("403036N1163036E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn test_fromstr_xxxxxx_xNxxxxxxx_xE() {
+    let result = Coordinate::from_str("403003.6N1163003.6E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.501,
+            lon: 116.501
+        }
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn test_fromstr_xxxxS_xxxxxW() {
+    let result = Coordinate::from_str("3330S 07040W").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: -33.5,
+            lon: -70.66666666666667
+        }
+    );
+}
+
+#[test]
+fn test_lon_error() {
+    let result = Coordinate::from_str("4030N1163X");
+    assert_eq!(result.unwrap_err().kind, CoordParseErrorKind::LonParseError);
+}
+
+#[test]
+fn test_lat_error() {
+    let result = Coordinate::from_str("403N11630E");
+    assert_eq!(result.unwrap_err().kind, CoordParseErrorKind::LatParseError);
+}
@@ -0,0 +1,168 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{CoordParseErrorKind, Coordinate};
+
+#[test]
+fn test_fromstr_dms_suffix() {
+    let result = Coordinate::from_str("40°30'36\"N 116°30'36\"E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_dms_prefix() {
+    let result = Coordinate::from_str("N40°30'36\" E116°30'36\"").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_dms_decimal() {
+    let result = Coordinate::from_str("40°30'03.6\"N 116°30'03.6\"E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.501,
+            lon: 116.501
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_dms_spaces() {
+    let result = Coordinate::from_str("40° 30' 36\" S 116° 30' 36\" W").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: -40.51,
+            lon: -116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_dms_comma() {
+    let result = Coordinate::from_str("40° 30' 36\" N, 116° 30' 36\" E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_dms_double_apostrophe() {
+    let result = Coordinate::from_str("40°30'36''N 116°30'36''E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_dms_unicode() {
+    let result = Coordinate::from_str("40°30′36″N 116°30′36″E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_dm() {
+    let result = Coordinate::from_str("40°30.6'N 116°30.6'E").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_chinese() {
+    let result = Coordinate::from_str("北纬40°30′36″ 东经116°30′36″").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_chinese_comma() {
+    let result = Coordinate::from_str("南纬40度30分36秒，西经116度30分36秒").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: -40.51,
+            lon: -116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_full_width() {
+    let result = Coordinate::from_str("Ｎ４０°３０′３６″　Ｅ１１６°３０′３６″").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_fromstr_full_width_plain() {
+    let result = Coordinate::from_str("Ｎ４０３０３６Ｅ１１６３０３６").unwrap();
+
+    assert_eq!(
+        result,
+        Coordinate {
+            lat: 40.51,
+            lon: 116.51
+        }
+    );
+}
+
+#[test]
+fn test_error_span() {
+    let err = Coordinate::from_str("北纬40°30′36″ 东经116°3X′36″").unwrap_err();
+
+    assert_eq!(err.kind, CoordParseErrorKind::LonParseError);
+    assert_eq!(err.span.start, 21);
+    assert_eq!(err.fragment(), "东经116°3X′36″");
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{Coordinate, Direction, Geohash, GeohashError};
+
+#[test]
+fn test_encode() {
+    let coord = Coordinate {
+        lat: 57.64911,
+        lon: 10.40744,
+    };
+    assert_eq!(coord.to_geohash(11).unwrap(), "u4pruydqqvj");
+    assert_eq!(coord.to_geohash(1).unwrap(), "u");
+    assert_eq!(
+        Coordinate {
+            lat: 42.605,
+            lon: -5.603
+        }
+        .to_geohash(5)
+        .unwrap(),
+        "ezs42"
+    );
+    assert_eq!(
+        Coordinate {
+            lat: -90.0,
+            lon: -180.0
+        }
+        .to_geohash(12)
+        .unwrap(),
+        "000000000000"
+    );
+    assert_eq!(
+        Coordinate {
+            lat: 90.0,
+            lon: 180.0
+        }
+        .to_geohash(3)
+        .unwrap(),
+        Coordinate {
+            lat: 90.0,
+            lon: -180.0
+        }
+        .to_geohash(3)
+        .unwrap()
+    );
+    assert!(Coordinate {
+        lat: 91.0,
+        lon: 0.0
+    }
+    .to_geohash(5)
+    .is_err());
+}
+
+#[test]
+fn test_decode() {
+    let hash = Geohash::from_str("ezs42").unwrap();
+    let bounds = hash.bounds();
+    assert_eq!(bounds.south, 42.5830078125);
+    assert_eq!(bounds.north, 42.626953125);
+    assert_eq!(bounds.west, -5.625);
+    assert_eq!(bounds.east, -5.5810546875);
+    assert!(bounds.contains(&Coordinate {
+        lat: 42.605,
+        lon: -5.603
+    }));
+
+    let center = Coordinate::from_geohash("EZS42").unwrap();
+    assert!((center.lat - 42.605).abs() < 0.001);
+    assert!((center.lon + 5.603).abs() < 0.001);
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(
+        Geohash::from_str("ezs4a"),
+        Err(GeohashError::InvalidCharacter('a'))
+    );
+    assert_eq!(Geohash::from_str(""), Err(GeohashError::InvalidLength(0)));
+    assert_eq!(
+        Geohash::from_str("0123456789bcd"),
+        Err(GeohashError::InvalidLength(13))
+    );
+}
+
+#[test]
+fn test_neighbors() {
+    let hash = Geohash::from_str("u4pruydqqvj").unwrap();
+    for direction in Direction::ALL {
+        let neighbor = hash.neighbor(direction).unwrap();
+        assert_ne!(neighbor, hash);
+        assert_eq!(neighbor.precision(), 11);
+    }
+    let east = hash.neighbor(Direction::East).unwrap();
+    assert_eq!(east.neighbor(Direction::West), Some(hash.clone()));
+    let north = hash.neighbor(Direction::North).unwrap();
+    assert_eq!(north.bounds().south, hash.bounds().north);
+}
+
+#[test]
+fn test_antimeridian_and_poles() {
+    let x = Geohash::from_str("x").unwrap();
+    assert_eq!(x.neighbor(Direction::East).unwrap().as_str(), "8");
+    assert_eq!(x.neighbor(Direction::North).unwrap().as_str(), "z");
+
+    let z = Geohash::from_str("z").unwrap();
+    let neighbors = z.neighbors();
+    assert_eq!(neighbors[0], None);
+    assert_eq!(neighbors[1], None);
+    assert_eq!(neighbors[7], None);
+    assert_eq!(neighbors[2].as_ref().unwrap().as_str(), "b");
+    assert_eq!(neighbors[4].as_ref().unwrap().as_str(), "x");
+}
@@ -0,0 +1,261 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{CoordFormat, Coordinate, GridError, Utm};
+
+fn assert_close(coord: Coordinate, lat: f64, lon: f64, tolerance: f64) {
+    assert!(
+        (coord.lat - lat).abs() <= tolerance && (coord.lon - lon).abs() <= tolerance,
+        "{:?} != ({}, {})",
+        coord,
+        lat,
+        lon
+    );
+}
+
+#[test]
+fn test_utm_forward() {
+    // GeoConvert -u: 33.3 44.4 => 38n 444140.54 3684706.36
+    let utm = Coordinate {
+        lat: 33.3,
+        lon: 44.4,
+    }
+    .to_utm()
+    .unwrap();
+    assert_eq!((utm.zone, utm.north), (38, true));
+    assert!((utm.easting - 444140.54).abs() < 0.005);
+    assert!((utm.northing - 3684706.36).abs() < 0.005);
+    assert_eq!(format!("{:.2}", utm), "38N 444140.54 3684706.36");
+
+    let utm = Coordinate { lat: 0.0, lon: 3.0 }.to_utm().unwrap();
+    assert_eq!(utm.to_string(), "31N 500000 0");
+}
+
+#[test]
+fn test_utm_reverse() {
+    // GeoConvert: 38n 444500 3688500 => 33.33424 44.40363
+    let coord = Utm::from_str("38n 444500 3688500")
+        .unwrap()
+        .to_coordinate()
+        .unwrap();
+    assert_close(coord, 33.33424, 44.40363, 0.000005);
+
+    let coord = Coordinate {
+        lat: -33.8568,
+        lon: 151.2153,
+    };
+    let utm = coord.to_utm().unwrap();
+    assert_eq!((utm.zone, utm.north), (56, false));
+    assert_close(utm.to_coordinate().unwrap(), coord.lat, coord.lon, 1e-9);
+}
+
+#[test]
+fn test_utm_zone_exceptions() {
+    let zone = |lat, lon| Coordinate { lat, lon }.to_utm().unwrap().zone;
+    assert_eq!(zone(60.0, 5.0), 32);
+    assert_eq!(zone(78.0, 15.0), 33);
+    assert_eq!(zone(78.0, 8.0), 31);
+    assert_eq!(zone(40.0, 180.0), 1);
+    assert_eq!(zone(84.0, 0.0), 0);
+    assert_eq!(zone(-80.5, 0.0), 0);
+}
+
+#[test]
+fn test_ups() {
+    let pole = Coordinate {
+        lat: 90.0,
+        lon: 0.0,
+    }
+    .to_utm()
+    .unwrap();
+    assert_eq!(pole.to_string(), "N 2000000 2000000");
+    assert_eq!(
+        Utm::from_str("S 2000000 2000000")
+            .unwrap()
+            .to_coordinate()
+            .unwrap()
+            .lat,
+        -90.0
+    );
+
+    for coord in [
+        Coordinate {
+            lat: 85.0,
+            lon: -120.0,
+        },
+        Coordinate {
+            lat: -87.5,
+            lon: 45.0,
+        },
+    ] {
+        let utm = coord.to_utm().unwrap();
+        assert_eq!(utm.zone, 0);
+        assert_close(utm.to_coordinate().unwrap(), coord.lat, coord.lon, 1e-9);
+    }
+}
+
+#[test]
+fn test_mgrs_decode() {
+    // GeoConvert: 38SMB4488 => 33.33424 44.40363
+    assert_close(
+        Coordinate::from_mgrs("38SMB4488").unwrap(),
+        33.33424,
+        44.40363,
+        0.000005,
+    );
+    assert_eq!(
+        Coordinate::from_mgrs("38smb 44 88"),
+        Coordinate::from_mgrs("38SMB4488")
+    );
+    let beijing = Coordinate::from_mgrs("50SMK 4873 2915").unwrap();
+    assert_eq!(format!("{:.4}", beijing), "40.0110,116.3993");
+    // 该方格跨越40°N，实际位置在T带
+    assert_eq!(beijing.to_mgrs(4).unwrap(), "50TMK48732915");
+    // 方格中心距北极约0.7米
+    let pole = Coordinate::from_mgrs("ZAH0000000000").unwrap();
+    assert!(pole.lat > 89.99999);
+}
+
+#[test]
+fn test_mgrs_encode() {
+    let coord = Coordinate {
+        lat: 33.3,
+        lon: 44.4,
+    };
+    assert_eq!(coord.to_mgrs(5).unwrap(), "38SMB4414084706");
+    assert_eq!(coord.to_mgrs(2).unwrap(), "38SMB4484");
+    assert_eq!(coord.to_mgrs(0).unwrap(), "38SMB");
+    assert_eq!(
+        Coordinate {
+            lat: 90.0,
+            lon: 0.0
+        }
+        .to_mgrs(5)
+        .unwrap(),
+        "ZAH0000000000"
+    );
+    assert_eq!(
+        Coordinate {
+            lat: -90.0,
+            lon: 0.0
+        }
+        .to_mgrs(5)
+        .unwrap(),
+        "BAN0000000000"
+    );
+    assert_eq!(
+        Coordinate {
+            lat: 91.0,
+            lon: 0.0
+        }
+        .to_mgrs(5)
+        .map_err(|e| matches!(e, GridError::OutOfRange(_))),
+        Err(true)
+    );
+}
+
+#[test]
+fn test_mgrs_southern_bands() {
+    for coord in [
+        Coordinate {
+            lat: -79.5,
+            lon: -170.0,
+        },
+        Coordinate {
+            lat: -34.6,
+            lon: -58.4,
+        },
+        Coordinate {
+            lat: -0.01,
+            lon: 36.8,
+        },
+        Coordinate {
+            lat: 83.9,
+            lon: 30.0,
+        },
+    ] {
+        let mgrs = coord.to_mgrs(5).unwrap();
+        assert_close(
+            Coordinate::from_mgrs(&mgrs).unwrap(),
+            coord.lat,
+            coord.lon,
+            0.0001,
+        );
+    }
+}
+
+#[test]
+fn test_parse_and_display() {
+    assert_close(
+        Coordinate::from_str("38SMB4488").unwrap(),
+        33.33424,
+        44.40363,
+        0.000005,
+    );
+    assert_close(
+        Coordinate::from_str("38N 444500 3688500").unwrap(),
+        33.33424,
+        44.40363,
+        0.000005,
+    );
+    assert_eq!(
+        Coordinate::parse_as("N4030.0E11630.0", CoordFormat::Mgrs).map_err(|e| e.kind),
+        Err(navdata_components::coordinate::CoordParseErrorKind::NoMatchingFormat)
+    );
+
+    let coord = Coordinate {
+        lat: 33.3,
+        lon: 44.4,
+    };
+    assert_eq!(
+        coord.display(CoordFormat::Mgrs).precision(2).to_string(),
+        "38SMB4484"
+    );
+    assert_eq!(
+        format!("{:.1}", coord.display(CoordFormat::Utm)),
+        "38N 444140.5 3684706.4"
+    );
+    assert_eq!(
+        coord.display(CoordFormat::Mgrs).precision(2).try_format(),
+        Ok("38SMB4484".to_string())
+    );
+
+    // 超出范围时改用十进制格式，try_format返回错误
+    let invalid = Coordinate {
+        lat: 91.0,
+        lon: 0.5,
+    };
+    for format in [CoordFormat::Utm, CoordFormat::Mgrs] {
+        assert_eq!(invalid.display(format).to_string(), "91.000000,00.500000");
+        assert!(matches!(
+            invalid.display(format).try_format(),
+            Err(GridError::OutOfRange(_))
+        ));
+    }
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(
+        Coordinate::from_mgrs("38SIB4488"),
+        Err(GridError::InvalidFormat)
+    );
+    assert_eq!(
+        Coordinate::from_mgrs("61SMB4488"),
+        Err(GridError::InvalidFormat)
+    );
+    assert_eq!(
+        Coordinate::from_mgrs("38SMB448"),
+        Err(GridError::InvalidFormat)
+    );
+    assert_eq!(
+        Utm::from_str("61N 444500 3688500"),
+        Err(GridError::InvalidZone(61))
+    );
+    assert_eq!(Utm::from_str("38N 444500"), Err(GridError::InvalidFormat));
+    assert_eq!(
+        Utm::from_str("38N 1444500 3688500")
+            .unwrap()
+            .to_coordinate(),
+        Err(GridError::OutOfZone)
+    );
+}
@@ -1,2 +1,16 @@
+pub mod axis_order;
 pub mod degree_minute_second;
+pub mod display;
+pub mod dms;
+pub mod format_detection;
 pub mod fromstr;
+pub mod fromstr_icao;
+pub mod fromstr_symbolic;
+pub mod geohash;
+pub mod grid;
+pub mod parse_error;
+pub mod parse_many;
+pub mod pbd;
+pub mod range;
+#[cfg(feature = "regex")]
+pub mod regex_parser;
@@ -0,0 +1,51 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{CoordFormat, CoordParseErrorKind, Coordinate};
+
+#[test]
+fn test_lat_span() {
+    let err = Coordinate::from_str("40.12l3456,116.654321").unwrap_err();
+
+    assert_eq!(err.kind, CoordParseErrorKind::LatParseError);
+    assert_eq!(err.span, 0..10);
+    assert_eq!(err.fragment(), "40.12l3456");
+    assert_eq!(err.closest, Some(CoordFormat::Decimal));
+}
+
+#[test]
+fn test_lon_span() {
+    let err = Coordinate::from_str("N400000E11600x0").unwrap_err();
+
+    assert_eq!(err.kind, CoordParseErrorKind::LonParseError);
+    assert_eq!(err.span, 7..15);
+    assert_eq!(err.fragment(), "E11600x0");
+    assert_eq!(err.input, "N400000E11600x0");
+}
+
+#[test]
+fn test_closest_format() {
+    let err = Coordinate::from_str("N4030.5xE11630.5").unwrap_err();
+
+    assert_eq!(err.kind, CoordParseErrorKind::LatParseError);
+    assert_eq!(err.fragment(), "N4030.5x");
+    assert_eq!(err.closest, Some(CoordFormat::DegreeMinute));
+}
+
+#[test]
+fn test_no_matching_format() {
+    let err = Coordinate::from_str("foo").unwrap_err();
+
+    assert_eq!(err.kind, CoordParseErrorKind::NoMatchingFormat);
+    assert_eq!(err.span, 0..3);
+    assert_eq!(err.closest, None);
+}
+
+#[test]
+fn test_display() {
+    let err = Coordinate::from_str("40.123456,116.6543l21").unwrap_err();
+    let message = err.to_string();
+
+    assert!(message.starts_with("invalid longitude \"116.6543l21\" at 10..21"));
+    assert!(message.contains("expected one of: [NSEW]DD(D)MM.M, "));
+    assert!(message.ends_with("(closest: [+-]DD(D).D)"));
+}
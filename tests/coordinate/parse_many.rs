@@ -0,0 +1,101 @@
+use navdata_components::algorithm::region::is_point_in_area;
+use navdata_components::coordinate::{AxisOrder, Coordinate, ParseOptions};
+
+#[test]
+fn test_notam_area() {
+    let area =
+        Coordinate::parse_many("400000N1160000E-401000N1162000E-402000N1161000E-400000N1160000E");
+    assert_eq!(area.len(), 4);
+    assert_eq!(
+        area[1],
+        Coordinate {
+            lat: 40.0 + 10.0 / 60.0,
+            lon: 116.0 + 20.0 / 60.0
+        }
+    );
+    assert_eq!(area[0], area[3]);
+
+    let inside = Coordinate {
+        lat: 40.1,
+        lon: 116.15,
+    };
+    let outside = Coordinate {
+        lat: 40.1,
+        lon: 116.5,
+    };
+    assert!(is_point_in_area(&inside, &area));
+    assert!(!is_point_in_area(&outside, &area));
+}
+
+#[test]
+fn test_spans() {
+    let text = "A line from N4000.0E11600.0 to N4010.0E11620.0; then 40.5,116.5";
+    let spans: Vec<&str> = Coordinate::find_iter(text, &ParseOptions::default())
+        .map(|(_, span)| &text[span])
+        .collect();
+    assert_eq!(spans, ["N4000.0E11600.0", "N4010.0E11620.0", "40.5,116.5"]);
+}
+
+#[test]
+fn test_separators() {
+    let expected = [
+        Coordinate {
+            lat: 40.5,
+            lon: 116.5,
+        },
+        Coordinate {
+            lat: -41.5,
+            lon: -117.5,
+        },
+        Coordinate {
+            lat: 42.5,
+            lon: 118.5,
+        },
+    ];
+    assert_eq!(
+        Coordinate::parse_many("40.5,116.5;-41.5,-117.5;42.5,118.5"),
+        expected
+    );
+    assert_eq!(
+        Coordinate::parse_many("N40.5 E116.5 - S41.5 W117.5 to N42.5 E118.5"),
+        expected
+    );
+    assert_eq!(
+        Coordinate::parse_many("N 40 30.0 E 116 30.0, S 41 30.0 W 117 30.0\nN 42 30.0 E 118 30.0"),
+        expected
+    );
+    assert_eq!(
+        Coordinate::parse_many("403000N 1163000E - 413000S 1173000W - 423000N 1183000E"),
+        expected
+    );
+}
+
+#[test]
+fn test_options() {
+    let options = ParseOptions {
+        axis_order: AxisOrder::LonLat,
+        ..Default::default()
+    };
+    let coords: Vec<Coordinate> = Coordinate::find_iter("116.5,40.5 117.5,41.5", &options)
+        .map(|(coord, _)| coord)
+        .collect();
+    assert_eq!(
+        coords,
+        [
+            Coordinate {
+                lat: 40.5,
+                lon: 116.5
+            },
+            Coordinate {
+                lat: 41.5,
+                lon: 117.5
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_no_coordinates() {
+    assert!(Coordinate::parse_many("").is_empty());
+    assert!(Coordinate::parse_many("RADIUS 5 NM, SFC TO 3000 M").is_empty());
+}
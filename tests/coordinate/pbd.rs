@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{Coordinate, Pbd, PbdError};
+
+fn pbd(ident: &str, bearing: f64, distance: f64) -> Pbd {
+    Pbd {
+        ident: ident.to_string(),
+        bearing,
+        distance,
+    }
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(Pbd::from_str("PEK045012").unwrap(), pbd("PEK", 45.0, 12.0));
+    assert_eq!(Pbd::from_str("pek045012").unwrap(), pbd("PEK", 45.0, 12.0));
+    assert_eq!(
+        Pbd::from_str("VYK360120").unwrap(),
+        pbd("VYK", 360.0, 120.0)
+    );
+    assert_eq!(
+        Pbd::from_str("ELNEX090005").unwrap(),
+        pbd("ELNEX", 90.0, 5.0)
+    );
+    assert_eq!(Pbd::from_str("PEK/045/12").unwrap(), pbd("PEK", 45.0, 12.0));
+    assert_eq!(
+        Pbd::from_str("PEK/45/12.5").unwrap(),
+        pbd("PEK", 45.0, 12.5)
+    );
+    assert_eq!(Pbd::from_str("PEK045/12").unwrap(), pbd("PEK", 45.0, 12.0));
+    assert_eq!(
+        Pbd::from_str(" PEK045/7.5 ").unwrap(),
+        pbd("PEK", 45.0, 7.5)
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(Pbd::from_str(""), Err(PbdError::InvalidFormat));
+    assert_eq!(Pbd::from_str("045012"), Err(PbdError::InvalidFormat));
+    assert_eq!(Pbd::from_str("PEK04512"), Err(PbdError::InvalidFormat));
+    assert_eq!(Pbd::from_str("PEK/ABC/12"), Err(PbdError::InvalidFormat));
+    assert_eq!(Pbd::from_str("PEK/045/-12"), Err(PbdError::InvalidFormat));
+    assert_eq!(Pbd::from_str("PEK/045/12/1"), Err(PbdError::InvalidFormat));
+    assert_eq!(Pbd::from_str("PEK361012"), Err(PbdError::BearingOutOfRange));
+    assert_eq!(
+        Pbd::from_str("ABCDEF045012"),
+        Err(PbdError::InvalidIdent("ABCDEF".to_string()))
+    );
+    assert_eq!(
+        Pbd::from_str("A045012"),
+        Err(PbdError::InvalidIdent("A".to_string()))
+    );
+    assert_eq!(
+        Pbd::from_str("A/045/12"),
+        Err(PbdError::InvalidIdent("A".to_string()))
+    );
+    assert_eq!(
+        Pbd::from_str("1AB045012"),
+        Err(PbdError::InvalidIdent("1AB".to_string()))
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(pbd("PEK", 45.0, 12.0).to_string(), "PEK045012");
+    assert_eq!(pbd("PEK", 45.0, 12.5).to_string(), "PEK/045/12.5");
+    for s in ["PEK045012", "PEK/045/12.5"] {
+        assert_eq!(Pbd::from_str(s).unwrap().to_string(), s);
+    }
+}
+
+#[test]
+fn test_resolve() {
+    let pek = Coordinate {
+        lat: 40.0725,
+        lon: 116.597222,
+    };
+    let lookup = |ident: &str| (ident == "PEK").then_some((pek, -7.0));
+
+    // 磁方位045°、磁偏角7°W，即真方位038°、22224米，期望值由高精度测地线积分独立计算
+    let result = Pbd::from_str("PEK045012").unwrap().resolve(lookup).unwrap();
+    assert!((result.lat - 40.2301077751106).abs() < 1e-11);
+    assert!((result.lon - 116.75799052517235).abs() < 1e-11);
+
+    assert_eq!(
+        Pbd::from_str("SHA090010").unwrap().resolve(lookup),
+        Err(PbdError::UnknownIdent("SHA".to_string()))
+    );
+}
@@ -0,0 +1,130 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{
+    CoordParseErrorKind, CoordRangeError, Coordinate, ParseOptions,
+};
+
+#[test]
+fn test_new() {
+    assert_eq!(
+        Coordinate::new(40.0, 116.0),
+        Ok(Coordinate {
+            lat: 40.0,
+            lon: 116.0
+        })
+    );
+    assert_eq!(
+        Coordinate::new(-90.0, 180.0),
+        Ok(Coordinate {
+            lat: -90.0,
+            lon: 180.0
+        })
+    );
+    assert_eq!(
+        Coordinate::new(99.0, 116.0),
+        Err(CoordRangeError::LatOutOfRange(99.0))
+    );
+    assert_eq!(
+        Coordinate::new(40.0, 196.0),
+        Err(CoordRangeError::LonOutOfRange(196.0))
+    );
+    assert!(Coordinate::new(f64::NAN, 116.0).is_err());
+}
+
+#[test]
+fn test_normalize() {
+    let c = Coordinate {
+        lat: 40.0,
+        lon: 190.0,
+    };
+    assert_eq!(
+        c.normalize(),
+        Ok(Coordinate {
+            lat: 40.0,
+            lon: -170.0
+        })
+    );
+
+    let c = Coordinate {
+        lat: 40.0,
+        lon: 180.0,
+    };
+    assert_eq!(c.normalize().unwrap().lon, -180.0);
+
+    let c = Coordinate {
+        lat: 40.0,
+        lon: -540.0,
+    };
+    assert_eq!(c.normalize().unwrap().lon, -180.0);
+
+    let c = Coordinate {
+        lat: 40.0,
+        lon: 116.0,
+    };
+    assert_eq!(c.normalize(), Ok(c));
+
+    let c = Coordinate {
+        lat: -91.0,
+        lon: 116.0,
+    };
+    assert_eq!(c.normalize(), Err(CoordRangeError::LatOutOfRange(-91.0)));
+}
+
+#[test]
+fn test_is_valid() {
+    assert!(Coordinate {
+        lat: 40.0,
+        lon: 116.0
+    }
+    .is_valid());
+    assert!(!Coordinate {
+        lat: 99.0,
+        lon: 116.0
+    }
+    .is_valid());
+}
+
+#[test]
+fn test_parse_unchecked() {
+    let result = Coordinate::from_str("N9900.0E1160000.0").unwrap();
+    assert_eq!(result.lat, 99.0);
+}
+
+#[test]
+fn test_parse_checked_lat() {
+    let options = ParseOptions {
+        check_range: true,
+        ..Default::default()
+    };
+    let err = Coordinate::parse_with("N9900.0E1160000.0", &options).unwrap_err();
+
+    assert_eq!(err.kind, CoordParseErrorKind::LatOutOfRange);
+    assert_eq!(err.fragment(), "N9900.0");
+}
+
+#[test]
+fn test_parse_checked_lon() {
+    let options = ParseOptions {
+        check_range: true,
+        ..Default::default()
+    };
+    let err = Coordinate::parse_with("40.0,196.0", &options).unwrap_err();
+
+    assert_eq!(err.kind, CoordParseErrorKind::LonOutOfRange);
+    assert_eq!(err.span, 5..10);
+}
+
+#[test]
+fn test_parse_checked_ok() {
+    let options = ParseOptions {
+        check_range: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        Coordinate::parse_with("N400000E1160000", &options),
+        Ok(Coordinate {
+            lat: 40.0,
+            lon: 116.0
+        })
+    );
+}
@@ -0,0 +1,52 @@
+use std::str::FromStr;
+
+use navdata_components::coordinate::{regex_parser, Coordinate};
+
+const INPUTS: [&str; 30] = [
+    "N400000E1160000",
+    "N403036E1163036",
+    "N400000.0E1160000.0",
+    "N403003.6E1163003.6",
+    "S403003.6W1163003.6",
+    "N39113625E116342500",
+    "N4030.60E11630.60",
+    "N4030.E11630.",
+    "N1030.5E01030.5",
+    "N040.30.03.600 E116.30.03.600",
+    "N040.30.03.60 E116.30.03.600",
+    "N 40 30.6 E 116 30.6",
+    "N 40 30.6E 116 30.6",
+    "40.123456,116.654321",
+    "-40.5,+116.5",
+    "-40.5 -116.5",
+    "40.12l3456,116.654321",
+    "40.123456,116.6543l21",
+    "4.5,116.5",
+    "1234.5,116.5",
+    "N4030.5xE11630.5",
+    "N40300E116300",
+    "E4000.0,N11600.0",
+    "NE",
+    "N\nE",
+    "40.5\n 116.5",
+    "",
+    "foo",
+    "N400000E1160000 ",
+    "N4000.0,E11600.0",
+];
+
+#[test]
+fn test_same_as_regex() {
+    for s in INPUTS {
+        let scanned = Coordinate::from_str(s);
+        let matched = regex_parser::parse(s);
+        match (scanned, matched) {
+            (Ok(a), Ok(b)) => assert_eq!(a, b, "{:?}", s),
+            (Err(a), Err(b)) => {
+                assert_eq!(a.kind, b.kind, "{:?}", s);
+                assert_eq!(a.span, b.span, "{:?}", s);
+            }
+            (a, b) => panic!("{:?}: {:?} != {:?}", s, a, b),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+"""生成tests/data/geodtest.dat：WGS-84测地线的高精度参考值
+
+按GeodTest（Karney, https://doi.org/10.5281/zenodo.32156）的方法：给定起点纬度、起点方位角和辅助球上的弧长，
+在辅助球上以50位有效数字对距离和经度差的积分做数值求积，不使用级数截断，得到终点、终点方位角和距离。
+弧长都小于180°，因此每一行都是最短测地线，可以同时用于正算和反算的测试。
+终点经纬度取f64能表示的值，方位角和距离用牛顿法对这一终点重新求解，与终点严格对应。
+
+分组与GeodTest-short.dat对应：随机、近对跖、短距离、起点在极点或接近极点、经线、赤道和接近赤道、终点接近极点。
+
+每行为`lat1 lon1 azi1 lat2 lon2 azi2 s12`，角度单位为度，距离单位为米，起点经度为0。
+
+运行：python3 tests/data/geodtest.py > tests/data/geodtest.dat（需要mpmath）。
+耗时较长，可以用`geodtest.py 起始行 结束行`分段生成后按顺序拼接
+"""
+
+import random
+import sys
+
+from mpmath import mp, mpf, sin, cos, atan, atan2, sqrt, quad, pi, degrees, radians
+
+mp.dps = 50
+A = mpf(6378137)
+F = 1 / mpf('298.257223563')
+B = A * (1 - F)
+E2 = F * (2 - F)
+EP2 = E2 / (1 - E2)
+
+
+def direct(lat1, azi1, sig12):
+    """给定辅助球上的弧长`sig12`（弧度）的正算，返回(lat2, lon12, azi2, s12)"""
+    lat1, azi1, sig12 = mpf(lat1), mpf(azi1), mpf(sig12)
+    west = sin(radians(azi1)) < 0
+    if west:
+        azi1 = -azi1
+    if abs(lat1) == 90:
+        # 极点处沿经线取极限
+        sphi1, cphi1 = mp.sign(lat1), mpf(10) ** -30
+    else:
+        sphi1, cphi1 = sin(radians(lat1)), cos(radians(lat1))
+    # 用正弦和余弦表示归化纬度，避免在极点附近损失有效数字
+    n = sqrt(((1 - F) * sphi1) ** 2 + cphi1 ** 2)
+    sbet1, cbet1 = (1 - F) * sphi1 / n, cphi1 / n
+    salp1, calp1 = sin(radians(azi1)), cos(radians(azi1))
+    salp0 = salp1 * cbet1
+    calp0 = sqrt(1 - salp0 ** 2)
+    sig1 = atan2(sbet1, calp1 * cbet1)
+    om1 = atan2(salp1 * sbet1, calp1)
+    sig2 = sig1 + sig12
+    k2 = EP2 * calp0 ** 2
+
+    def ds(t):
+        return sqrt(1 + k2 * sin(t) ** 2)
+
+    nodes = mp.linspace(sig1, sig2, 2 + int(8 * sig12))
+    s12 = B * quad(ds, nodes)
+
+    # ω与σ在同一象限
+    om2 = atan2(salp0 * sin(sig2), cos(sig2))
+    om2 += 2 * pi * mp.nint((sig2 - om2) / (2 * pi))
+    om1 += 2 * pi * mp.nint((sig1 - om1) / (2 * pi))
+    lam12 = om2 - om1 - F * salp0 * quad(lambda t: (2 - F) / (1 + (1 - F) * ds(t)), nodes)
+    beta2 = atan2(calp0 * sin(sig2), sqrt((calp0 * cos(sig2)) ** 2 + salp0 ** 2))
+    phi2 = atan(sin(beta2) / cos(beta2) / (1 - F))
+    alp2 = atan2(salp0, calp0 * cos(sig2))
+    lat2, lon12, azi2 = degrees(phi2), degrees(lam12), degrees(alp2)
+    if west:
+        lon12, azi2 = -lon12, -azi2
+    return lat2, lon12, azi2, s12
+
+
+def inverse(lat1, lat2, lon2, azi1, sig12):
+    """以正算的参数为初值，用牛顿法求终点恰好为`(lat2, lon2)`的测地线，返回(azi1, azi2, s12)。
+    终点经纬度取f64能表示的值，短距离和近对跖时方位角对终点的微小变化很敏感，不能直接使用正算的参数"""
+    lat2, lon2, azi1, sig12 = mpf(lat2), mpf(lon2), mpf(azi1), mpf(sig12)
+
+    def residual(azi1, sig12):
+        lat, lon, _, _ = direct(lat1, azi1, sig12)
+        return mp.matrix([lat - lat2, wrap(lon - lon2)])
+
+    h = mpf(10) ** -18
+    for _ in range(10):
+        r = residual(azi1, sig12)
+        if mp.norm(r) < mpf(10) ** -32:
+            break
+        j = mp.matrix(2, 2)
+        for k, (da, ds) in enumerate([(h, 0), (0, h * sig12)]):
+            col = (residual(azi1 + da, sig12 + ds) - residual(azi1 - da, sig12 - ds)) / (2 * (da + ds))
+            j[0, k], j[1, k] = col[0], col[1]
+        step = mp.lu_solve(j, -r)
+        azi1 += step[0]
+        sig12 += step[1]
+    else:
+        raise RuntimeError('inverse did not converge')
+    _, _, azi2, s12 = direct(lat1, azi1, sig12)
+    return wrap(azi1), wrap(azi2), s12
+
+
+def wrap(x):
+    """规范化到(-180, 180]"""
+    x = x - 360 * mp.nint(x / 360)
+    return x + 360 if x <= -180 else x
+
+
+def rows(rng):
+    uniform = rng.uniform
+
+    def rand_lat():
+        return float(degrees(mp.asin(uniform(-1, 1))))
+
+    def rand_sig():
+        return uniform(0, 0.999999) * float(pi)
+
+    # 随机
+    for _ in range(200):
+        yield rand_lat(), uniform(-180, 180), rand_sig()
+    # 近对跖：弧长与180°只差1e-6~1e-2弧度
+    for _ in range(150):
+        yield rand_lat(), uniform(-180, 180), float(pi) - 10 ** uniform(-6, -2)
+    # 短距离：约1毫米到100千米
+    for _ in range(150):
+        yield rand_lat(), uniform(-180, 180), 10 ** uniform(-10, -1.8)
+    # 起点在极点
+    for _ in range(40):
+        yield rng.choice([90.0, -90.0]), uniform(-180, 180), rand_sig()
+    # 起点接近极点
+    for _ in range(60):
+        yield rng.choice([1, -1]) * (90 - 10 ** uniform(-9, 0)), uniform(-180, 180), rand_sig()
+    # 经线，包括越过极点
+    for _ in range(100):
+        yield rand_lat(), rng.choice([0.0, 180.0]), rand_sig()
+    # 赤道
+    for _ in range(40):
+        yield 0.0, rng.choice([90.0, -90.0]), rand_sig()
+    # 接近赤道
+    for _ in range(60):
+        yield uniform(-1e-3, 1e-3), rng.choice([1, -1]) * (90 + uniform(-1e-3, 1e-3)), rand_sig()
+    # 终点接近极点：方位角接近南北向，弧长使终点接近辅助球上的极点
+    for _ in range(60):
+        lat1 = uniform(-80, 80)
+        azi1 = rng.choice([1, -1]) * 10 ** uniform(-7, -1)
+        beta1 = float(atan((1 - F) * mp.tan(radians(lat1))))
+        yield lat1, azi1, float(pi) / 2 - beta1 + uniform(-1e-4, 1e-4)
+
+
+def main():
+    rng = random.Random(20261018)
+    start, end = (int(arg) for arg in sys.argv[1:3]) if len(sys.argv) > 2 else (0, None)
+    for k, (lat1, azi1, sig12) in enumerate(rows(rng)):
+        if k < start or (end is not None and k >= end):
+            continue
+        lat2, lon12, _, _ = direct(lat1, azi1, sig12)
+        # 终点取f64能表示的值，再反算出与之严格对应的方位角和距离
+        lat2, lon2 = float(lat2), float(wrap(lon12))
+        azi1, azi2, s12 = inverse(lat1, lat2, lon2, azi1, sig12)
+        print(
+            repr(lat1), 0, mp.nstr(azi1, 20), repr(lat2), repr(lon2),
+            mp.nstr(azi2, 20), mp.nstr(s12, 20), flush=True,
+        )
+
+
+if __name__ == '__main__':
+    main()
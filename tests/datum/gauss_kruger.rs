@@ -0,0 +1,71 @@
+use navdata_components::coordinate::GridError;
+use navdata_components::datum::{GaussKruger, ZoneWidth};
+use navdata_components::{Coordinate, Ellipsoid};
+
+#[test]
+fn test_zones() {
+    assert_eq!(ZoneWidth::Six.zone_of(116.4), 20);
+    assert_eq!(ZoneWidth::Six.central_meridian(20), 117.0);
+    assert_eq!(ZoneWidth::Three.zone_of(116.4), 39);
+    assert_eq!(ZoneWidth::Three.central_meridian(39), 117.0);
+    assert_eq!(ZoneWidth::Three.zone_of(0.5), 120);
+    assert_eq!(ZoneWidth::Three.central_meridian(120), 0.0);
+    assert_eq!(ZoneWidth::Six.zone_of(-3.5), 60);
+    assert_eq!(ZoneWidth::Six.central_meridian(60), -3.0);
+}
+
+#[test]
+fn test_central_meridian() {
+    // 中央经线上比例因子为1，北坐标为子午线弧长，与UTM相差0.9996倍
+    let coord = Coordinate {
+        lat: 40.0,
+        lon: 117.0,
+    };
+    let gk = GaussKruger::from_coordinate(&coord, &Ellipsoid::WGS84, ZoneWidth::Six).unwrap();
+    assert_eq!(gk.zone, 20);
+    assert!((gk.easting - 500_000.0).abs() < 1e-6);
+    let utm = coord.to_utm().unwrap();
+    assert!((gk.northing * 0.9996 - utm.northing).abs() < 1e-6);
+}
+
+#[test]
+fn test_round_trip() {
+    let coord = Coordinate {
+        lat: 40.0725,
+        lon: 116.5975,
+    };
+    for width in [ZoneWidth::Three, ZoneWidth::Six] {
+        let gk = GaussKruger::from_coordinate(&coord, &Ellipsoid::CGCS2000, width).unwrap();
+        let back = gk.to_coordinate(&Ellipsoid::CGCS2000).unwrap();
+        assert!((back.lat - coord.lat).abs() < 1e-10);
+        assert!((back.lon - coord.lon).abs() < 1e-10);
+    }
+
+    // 相邻带
+    let gk =
+        GaussKruger::from_coordinate_in_zone(&coord, &Ellipsoid::KRASOVSKY, ZoneWidth::Three, 38)
+            .unwrap();
+    assert!(gk.easting > 500_000.0 + 200_000.0);
+    let back = gk.to_coordinate(&Ellipsoid::KRASOVSKY).unwrap();
+    assert!((back.lat - coord.lat).abs() < 1e-10);
+    assert!((back.lon - coord.lon).abs() < 1e-10);
+}
+
+#[test]
+fn test_prefixed_easting() {
+    let gk = GaussKruger::from_prefixed(ZoneWidth::Three, 4_436_000.0, 39_551_000.0).unwrap();
+    assert_eq!(gk.zone, 39);
+    assert_eq!(gk.easting, 551_000.0);
+    assert_eq!(gk.prefixed_easting(), 39_551_000.0);
+    let coord = gk.to_coordinate(&Ellipsoid::CGCS2000).unwrap();
+    assert!((coord.lon - 117.6).abs() < 0.01);
+    let again =
+        GaussKruger::from_coordinate(&coord, &Ellipsoid::CGCS2000, ZoneWidth::Three).unwrap();
+    assert!((again.prefixed_easting() - 39_551_000.0).abs() < 1e-6);
+    assert!((again.northing - 4_436_000.0).abs() < 1e-6);
+
+    assert_eq!(
+        GaussKruger::from_prefixed(ZoneWidth::Six, 4_436_000.0, 61_500_000.0),
+        Err(GridError::InvalidZone(61))
+    );
+}
@@ -0,0 +1,146 @@
+use navdata_components::datum::{
+    bd09_to_gcj02, bd09_to_wgs84, gcj02_to_bd09, gcj02_to_wgs84, is_in_china, transform,
+    transform_slice, wgs84_to_bd09, wgs84_to_gcj02, MapSystem, INVERSE_TOLERANCE,
+};
+use navdata_components::Coordinate;
+
+/// (WGS-84, GCJ-02)
+const SAMPLES: [(Coordinate, Coordinate); 3] = [
+    (
+        Coordinate {
+            lat: 31.1774276,
+            lon: 121.5272106,
+        },
+        Coordinate {
+            lat: 31.17530398364597,
+            lon: 121.531541859215,
+        },
+    ),
+    (
+        Coordinate {
+            lat: 22.543847,
+            lon: 113.912316,
+        },
+        Coordinate {
+            lat: 22.540796131694766,
+            lon: 113.9171764808363,
+        },
+    ),
+    (
+        Coordinate {
+            lat: 39.911954,
+            lon: 116.377817,
+        },
+        Coordinate {
+            lat: 39.91334545536069,
+            lon: 116.38404722455657,
+        },
+    ),
+];
+
+fn assert_close(a: Coordinate, b: Coordinate, tolerance: f64) {
+    assert!(
+        (a.lat - b.lat).abs() <= tolerance && (a.lon - b.lon).abs() <= tolerance,
+        "{:?} != {:?}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn test_wgs84_to_gcj02() {
+    for (wgs84, gcj02) in SAMPLES {
+        assert_close(wgs84_to_gcj02(&wgs84), gcj02, 1e-9);
+    }
+}
+
+#[test]
+fn test_gcj02_to_wgs84() {
+    for (wgs84, gcj02) in SAMPLES {
+        let inverse = gcj02_to_wgs84(&gcj02);
+        assert_close(inverse, wgs84, 1e-8);
+        assert_close(wgs84_to_gcj02(&inverse), gcj02, INVERSE_TOLERANCE);
+    }
+}
+
+#[test]
+fn test_gcj02_to_wgs84_near_boundary() {
+    // 偏移向东北，范围西南角附近的反算结果落在范围以外，迭代不能因此振荡
+    let corner = Coordinate {
+        lat: 0.83,
+        lon: 72.0045,
+    };
+    let inside = Coordinate {
+        lat: 0.833,
+        lon: 72.009,
+    };
+    let (corner_wgs84, inside_wgs84) = (gcj02_to_wgs84(&corner), gcj02_to_wgs84(&inside));
+    assert!(!is_in_china(&corner_wgs84));
+    assert!(is_in_china(&inside_wgs84));
+    assert_close(wgs84_to_gcj02(&inside_wgs84), inside, INVERSE_TOLERANCE);
+
+    // 偏移随位置缓慢变化，两点的偏移应当接近
+    let offset = |gcj02: Coordinate, wgs84: Coordinate| Coordinate {
+        lat: gcj02.lat - wgs84.lat,
+        lon: gcj02.lon - wgs84.lon,
+    };
+    assert!(offset(corner, corner_wgs84).lon > 3e-3);
+    assert_close(
+        offset(corner, corner_wgs84),
+        offset(inside, inside_wgs84),
+        1e-4,
+    );
+}
+
+#[test]
+fn test_bd09() {
+    // 百度地图天安门
+    let bd09 = Coordinate {
+        lat: 39.915,
+        lon: 116.404,
+    };
+    let gcj02 = bd09_to_gcj02(&bd09);
+    assert_close(
+        gcj02,
+        Coordinate {
+            lat: 39.90865,
+            lon: 116.39763,
+        },
+        1e-5,
+    );
+    assert_close(gcj02_to_bd09(&gcj02), bd09, INVERSE_TOLERANCE);
+
+    let wgs84 = bd09_to_wgs84(&bd09);
+    assert_close(wgs84_to_bd09(&wgs84), bd09, 2.0 * INVERSE_TOLERANCE);
+}
+
+#[test]
+fn test_outside_china() {
+    let coord = Coordinate {
+        lat: 51.4775,
+        lon: -0.4614,
+    };
+    assert_eq!(wgs84_to_gcj02(&coord), coord);
+    assert_eq!(gcj02_to_wgs84(&coord), coord);
+}
+
+#[test]
+fn test_transform() {
+    let (wgs84, gcj02) = SAMPLES[2];
+    assert_eq!(transform(&wgs84, MapSystem::Wgs84, MapSystem::Wgs84), wgs84);
+    assert_eq!(
+        transform(&wgs84, MapSystem::Wgs84, MapSystem::Gcj02),
+        wgs84_to_gcj02(&wgs84)
+    );
+    assert_eq!(
+        transform(&gcj02, MapSystem::Gcj02, MapSystem::Bd09),
+        gcj02_to_bd09(&gcj02)
+    );
+
+    let mut coords = SAMPLES.map(|(wgs84, _)| wgs84);
+    transform_slice(&mut coords, MapSystem::Wgs84, MapSystem::Bd09);
+    transform_slice(&mut coords, MapSystem::Bd09, MapSystem::Wgs84);
+    for (coord, (wgs84, _)) in coords.into_iter().zip(SAMPLES) {
+        assert_close(coord, wgs84, 1e-8);
+    }
+}
@@ -0,0 +1,89 @@
+use navdata_components::datum::{Datum, DatumError, Helmert};
+use navdata_components::{Coordinate, Ellipsoid};
+
+fn assert_close(a: Coordinate, b: Coordinate, tolerance: f64) {
+    assert!(
+        (a.lat - b.lat).abs() <= tolerance && (a.lon - b.lon).abs() <= tolerance,
+        "{:?} != {:?}",
+        a,
+        b
+    );
+}
+
+const ZBAA: Coordinate = Coordinate {
+    lat: 40.0725,
+    lon: 116.5975,
+};
+
+#[test]
+fn test_ecef() {
+    // 赤道、本初子午线上的点
+    let xyz = Ellipsoid::WGS84.to_ecef(&Coordinate { lat: 0.0, lon: 0.0 }, 0.0);
+    assert_eq!(xyz, [6378137.0, 0.0, 0.0]);
+
+    let xyz = Ellipsoid::WGS84.to_ecef(
+        &Coordinate {
+            lat: 90.0,
+            lon: 0.0,
+        },
+        100.0,
+    );
+    assert!((xyz[2] - (Ellipsoid::WGS84.b() + 100.0)).abs() < 1e-6);
+
+    let xyz = Ellipsoid::KRASOVSKY.to_ecef(&ZBAA, 1234.5);
+    let (coord, height) = Ellipsoid::KRASOVSKY.from_ecef(xyz);
+    assert_close(coord, ZBAA, 1e-11);
+    assert!((height - 1234.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_helmert_inverse() {
+    let helmert = Helmert {
+        tx: -24.0,
+        ty: 123.0,
+        tz: 94.0,
+        rx: 0.02,
+        ry: -0.25,
+        rz: -0.13,
+        ds: 1.1,
+    };
+    let xyz = Ellipsoid::WGS84.to_ecef(&ZBAA, 0.0);
+    let back = helmert.apply_inverse(helmert.apply(xyz));
+    for (a, b) in back.iter().zip(xyz) {
+        assert!((a - b).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_cgcs2000() {
+    let coord = Datum::CGCS2000.to_wgs84(&ZBAA).unwrap();
+    assert_close(coord, ZBAA, 1e-8);
+}
+
+#[test]
+fn test_beijing1954() {
+    let wgs84 = Datum::BEIJING1954.to_wgs84(&ZBAA).unwrap();
+    // 北京地区两者相差约数十米
+    let dlat = (wgs84.lat - ZBAA.lat) * 111_000.0;
+    let dlon = (wgs84.lon - ZBAA.lon) * 111_000.0 * ZBAA.lat.to_radians().cos();
+    assert!((10.0..200.0).contains(&dlat.hypot(dlon)));
+
+    assert_close(Datum::BEIJING1954.from_wgs84(&wgs84).unwrap(), ZBAA, 1e-7);
+    assert_close(
+        Datum::WGS84.transform(&wgs84, &Datum::BEIJING1954).unwrap(),
+        ZBAA,
+        1e-7,
+    );
+}
+
+#[test]
+fn test_xian1980() {
+    assert_eq!(
+        Datum::XIAN1980.to_wgs84(&ZBAA),
+        Err(DatumError::MissingParameters)
+    );
+    let local = Datum::XIAN1980.with_helmert(Helmert::translation(10.0, -20.0, 30.0));
+    let wgs84 = local.to_wgs84(&ZBAA).unwrap();
+    assert_close(local.from_wgs84(&wgs84).unwrap(), ZBAA, 1e-7);
+    assert!(Datum::BEIJING1954.transform(&ZBAA, &local).is_ok());
+}
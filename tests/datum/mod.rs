@@ -0,0 +1,3 @@
+mod gauss_kruger;
+mod gcj02;
+mod helmert;
@@ -1,2 +1,4 @@
 pub mod algorithm;
 pub mod coordinate;
+pub mod datum;
+pub mod magnetic;
@@ -0,0 +1,211 @@
+use navdata_components::algorithm::bearing::MagneticBearing;
+use navdata_components::algorithm::measurement::{
+    get_angle, get_angle_wmm, get_magnetic_bearing_wmm, get_rhumb_bearing, get_rhumb_bearing_wmm,
+};
+use navdata_components::algorithm::position_transformation::{
+    get_colocated_coord, get_colocated_coord_wmm, get_rhumb_colocated_coord,
+    get_rhumb_colocated_coord_wmm,
+};
+use navdata_components::coordinate::Coordinate;
+use navdata_components::magnetic::{decimal_year, get_mag_var, MagneticModel, MagneticModelError};
+
+fn coord(lat: f64, lon: f64) -> Coordinate {
+    Coordinate { lat, lon }
+}
+
+/// NOAA发布的WMM2020系数，用于对照同时发布的测试值
+const WMM2020_COF: &str = include_str!("../data/WMM2020.COF");
+
+/// 对照NOAA发布的测试值，每行为`年份 椭球高（千米） 纬度 经度 X Y Z D I`，
+/// 测试值保留1位小数（nT）和2位小数（度）
+fn check_test_values(model: &MagneticModel, rows: &[[f64; 9]]) {
+    for &[year, altitude, lat, lon, x, y, z, d, i] in rows {
+        let raw = coord(lat, lon);
+        let field = model.field(&raw, altitude * 1e3, year);
+        assert!((field.north - x).abs() < 0.06, "{} {:?}", year, field);
+        assert!((field.east - y).abs() < 0.06, "{} {:?}", year, field);
+        assert!((field.down - z).abs() < 0.06, "{} {:?}", year, field);
+        assert!(
+            (field.inclination() - i).abs() < 0.006,
+            "{} {:?}",
+            year,
+            field
+        );
+        let result = model.declination(&raw, altitude * 1e3, year);
+        assert!(
+            (result - d).abs() < 0.006,
+            "{} {} {}",
+            year,
+            altitude,
+            result
+        );
+    }
+}
+
+// NOAA发布的WMM2020全部测试值：历元和年中、椭球高0和100千米，检验年变化率和高度的处理
+#[test]
+fn test_wmm2020_test_values() {
+    let model = MagneticModel::from_cof(WMM2020_COF).unwrap();
+    assert_eq!(model.name, "WMM-2020");
+    check_test_values(
+        &model,
+        &[
+            [
+                2020.0, 0.0, 80.0, 0.0, 6570.4, -146.3, 54606.0, -1.28, 83.14,
+            ],
+            [
+                2020.0, 0.0, 0.0, 120.0, 39624.3, 109.9, -10932.5, 0.16, -15.42,
+            ],
+            [
+                2020.0, 0.0, -80.0, 240.0, 5940.6, 15772.1, -52480.8, 69.36, -72.20,
+            ],
+            [
+                2020.0, 100.0, 80.0, 0.0, 6261.8, -185.5, 52429.1, -1.70, 83.19,
+            ],
+            [
+                2020.0, 100.0, 0.0, 120.0, 37636.7, 104.9, -10474.8, 0.16, -15.55,
+            ],
+            [
+                2020.0, 100.0, -80.0, 240.0, 5744.9, 14799.5, -49969.4, 68.78, -72.37,
+            ],
+            [2022.5, 0.0, 80.0, 0.0, 6529.9, 1.1, 54713.4, 0.01, 83.19],
+            [
+                2022.5, 0.0, 0.0, 120.0, 39684.7, -42.2, -10809.5, -0.06, -15.24,
+            ],
+            [
+                2022.5, 0.0, -80.0, 240.0, 6016.5, 15776.7, -52251.6, 69.13, -72.09,
+            ],
+            [
+                2022.5, 100.0, 80.0, 0.0, 6224.0, -44.5, 52527.0, -0.41, 83.24,
+            ],
+            [
+                2022.5, 100.0, 0.0, 120.0, 37694.0, -35.3, -10362.0, -0.05, -15.37,
+            ],
+            [
+                2022.5, 100.0, -80.0, 240.0, 5815.0, 14803.0, -49755.3, 68.55, -72.27,
+            ],
+        ],
+    );
+}
+
+// NOAA随WMM2025发布的测试值（WMM2025_TEST_VALUES），对照内置系数：2025.0和2027.5，椭球高0和100千米。
+// 测试值放在tests/data/WMM2025_TEST_VALUES.txt，格式同`check_test_values`，`#`开头的行为注释
+#[test]
+#[ignore = "needs NOAA's WMM2025 test values in tests/data/WMM2025_TEST_VALUES.txt"]
+fn test_wmm2025_test_values() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/WMM2025_TEST_VALUES.txt"
+    );
+    let data = std::fs::read_to_string(path).unwrap();
+    let rows: Vec<[f64; 9]> = data
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let row: Vec<f64> = line
+                .split_whitespace()
+                .map(|value| value.parse().unwrap())
+                .collect();
+            row.try_into().unwrap()
+        })
+        .collect();
+    assert_eq!(rows.len(), 12);
+    check_test_values(MagneticModel::wmm(), &rows);
+}
+
+#[test]
+fn test_wmm() {
+    let model = MagneticModel::wmm();
+    assert_eq!(model.name, "WMM-2025");
+    assert_eq!(model.epoch, 2025.0);
+    assert_eq!(model.degree(), 12);
+    assert_eq!(model.valid_until(), 2030.0);
+    assert!(model.is_valid(2025.0));
+    assert!(model.is_valid(decimal_year(2029, 12, 31)));
+    assert!(!model.is_valid(2024.99));
+    assert!(!model.is_valid(2030.0));
+
+    // WMM2020外推到2025.0与WMM2025的差异为预报误差，全球不超过几百nT
+    let wmm2020 = MagneticModel::from_cof(WMM2020_COF).unwrap();
+    for lat in (-80..=80).step_by(10) {
+        for lon in (-180..180).step_by(15) {
+            let raw = coord(lat as f64, lon as f64);
+            let a = model.field(&raw, 0.0, 2025.0);
+            let b = wmm2020.field(&raw, 0.0, 2025.0);
+            let diff = (a.north - b.north)
+                .hypot(a.east - b.east)
+                .hypot(a.down - b.down);
+            assert!(diff < 500.0, "{} {} {}", lat, lon, diff);
+        }
+    }
+
+    // 高度增加时场强减弱，年变化率使磁偏角随时间变化
+    let raw = coord(40.07, 116.6);
+    let ground = model.field(&raw, 0.0, 2025.0).total();
+    assert!(model.field(&raw, 100e3, 2025.0).total() < ground);
+    assert_ne!(
+        model.declination(&raw, 0.0, 2025.0),
+        model.declination(&raw, 0.0, 2029.0)
+    );
+}
+
+#[test]
+fn test_decimal_year() {
+    assert_eq!(decimal_year(2020, 1, 1), 2020.0);
+    assert_eq!(decimal_year(2020, 7, 2), 2020.5);
+    assert_eq!(decimal_year(2021, 12, 31), 2021.0 + 364.0 / 365.0);
+}
+
+#[test]
+fn test_from_cof() {
+    let cof = "    2020.0            TEST        12/10/2019\n  1  0  -29404.5       0.0        6.7        0.0\n999999999999\n";
+    let model = MagneticModel::from_cof(cof).unwrap();
+    assert_eq!(model.name, "TEST");
+    assert_eq!(model.degree(), 1);
+    // 只有轴向偶极子时磁偏角为0
+    assert!(model.declination(&coord(40.0, 116.0), 0.0, 2021.0).abs() < 1e-12);
+
+    assert_eq!(
+        MagneticModel::from_cof(""),
+        Err(MagneticModelError::InvalidHeader)
+    );
+    assert_eq!(
+        MagneticModel::from_cof("2020.0 TEST\n"),
+        Err(MagneticModelError::MissingCoefficients)
+    );
+    assert_eq!(
+        MagneticModel::from_cof("2020.0 TEST\n  1  0  -29404.5\n"),
+        Err(MagneticModelError::InvalidLine(2))
+    );
+}
+
+#[test]
+fn test_wmm_variants() {
+    let raw = coord(40.07, 116.6);
+    let target = coord(31.14, 121.8);
+    let year = decimal_year(2026, 3, 15);
+    let mag_var = get_mag_var(&raw, year);
+    // 北京附近为西偏约7°
+    assert!(mag_var < -6.0 && mag_var > -9.0, "{}", mag_var);
+
+    assert_eq!(
+        get_angle_wmm(&raw, &target, year),
+        get_angle(&raw, &target, mag_var)
+    );
+    assert_eq!(
+        get_magnetic_bearing_wmm(&raw, &target, year),
+        get_angle(&raw, &target, mag_var)
+    );
+    assert_eq!(
+        get_rhumb_bearing_wmm(&raw, &target, year),
+        get_rhumb_bearing(&raw, &target, mag_var)
+    );
+    assert_eq!(
+        get_colocated_coord_wmm(&raw, MagneticBearing::new(45.0), 22224.0, year),
+        get_colocated_coord(&raw, MagneticBearing::new(45.0), 22224.0, mag_var)
+    );
+    assert_eq!(
+        get_rhumb_colocated_coord_wmm(&raw, MagneticBearing::new(45.0), 22224.0, year),
+        get_rhumb_colocated_coord(&raw, MagneticBearing::new(45.0), 22224.0, mag_var)
+    );
+}